@@ -1,7 +1,9 @@
 use hdk::prelude::*;
+use std::collections::HashMap;
 use stewardship_integrity::*;
 #[hdk_extern]
-pub fn create_actant(actant: Actant) -> ExternResult<Record> {
+pub fn create_actant(mut actant: Actant) -> ExternResult<Record> {
+    cite_guardian_revisions(&mut actant)?;
     let actant_hash = create_entry(&EntryTypes::Actant(actant.clone()))?;
     let record = get(actant_hash.clone(), GetOptions::default())?
         .ok_or(
@@ -9,26 +11,168 @@ pub fn create_actant(actant: Actant) -> ExternResult<Record> {
                 WasmErrorInner::Guest(String::from("Could not find the newly created Actant"))
             ),
         )?;
-    let path = Path::from("all_actants");
-    create_link(
-        path.path_entry_hash()?,
-        actant_hash.clone(),
+    crate::time_shard::link_to_time_shard(
+        "all_actants",
+        LinkTypes::AllActantsShards,
         LinkTypes::AllActants,
-        (),
+        &record,
     )?;
+    link_to_actant(&actant_hash, &actant_hash)?;
+    crate::invitation::invite_added_agents(&actant_hash, &[], &actant_hash, &actant)?;
     Ok(record)
 }
+/// Cites the latest revisions of the guardians, replacing any the caller copied over from
+/// an earlier revision of the Actant
+fn cite_guardian_revisions(actant: &mut Actant) -> ExternResult<()> {
+    actant.guardian_revision_hashes = get_latest_revision_hashes(&actant.guardians)?;
+    Ok(())
+}
+/// Links the calling agent to the Actant it became a member of through the given revision,
+/// by creating it or by accepting an invitation to it
+pub fn link_to_actant(original_actant_hash: &ActionHash, revision_hash: &ActionHash) -> ExternResult<()> {
+    let tag: SerializedBytes = AgentToActantTag {
+        actant_revision_hash: revision_hash.clone(),
+    }
+        .try_into()
+        .map_err(|e: SerializedBytesError| wasm_error!(e))?;
+    create_link(
+        agent_info()?.agent_initial_pubkey,
+        original_actant_hash.clone(),
+        LinkTypes::AgentToActants,
+        LinkTag::new(tag.bytes().clone()),
+    )?;
+    Ok(())
+}
+/// Removes the links of the agents an Actant revision removed. Added agents link
+/// themselves once they accept their invitation.
+fn unlink_removed_agents(
+    original_actant_hash: &ActionHash,
+    previous_agents: &[AgentPubKey],
+    actant: &Actant,
+) -> ExternResult<()> {
+    for agent in previous_agents.iter().filter(|agent| !actant.agents.contains(agent)) {
+        let links = get_links(agent.clone(), LinkTypes::AgentToActants, None)?;
+        for link in links {
+            if ActionHash::from(link.target) == *original_actant_hash {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+    }
+    Ok(())
+}
+fn get_actant_agents(actant_hash: &ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+    let actant: Actant = get(actant_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the previous Actant"))
+            ),
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Previous record must be an Actant"))
+            ),
+        )?;
+    Ok(actant.agents)
+}
 #[hdk_extern]
 pub fn get_actant(original_actant_hash: ActionHash) -> ExternResult<Option<Record>> {
-    let links = get_links(original_actant_hash.clone(), LinkTypes::ActantUpdates, None)?;
-    let latest_link = links
+    let heads = get_actant_heads(original_actant_hash)?;
+    Ok(heads.map(|heads| heads.latest))
+}
+/// Latest revisions of the given Actants and of their guardians, for entries to cite the
+/// revisions their author acts as
+pub fn get_latest_revision_hashes(actant_hashes: &[ActionHash]) -> ExternResult<Vec<ActionHash>> {
+    let mut revision_hashes: Vec<ActionHash> = Vec::new();
+    let mut pending: Vec<ActionHash> = actant_hashes.to_vec();
+    let mut visited: Vec<ActionHash> = Vec::new();
+    while let Some(actant_hash) = pending.pop() {
+        if visited.contains(&actant_hash) {
+            continue;
+        }
+        visited.push(actant_hash.clone());
+        let latest = match get_actant(actant_hash)? {
+            Some(latest) => latest,
+            None => continue,
+        };
+        revision_hashes.push(latest.action_address().clone());
+        let actant: Option<Actant> = latest
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        if let Some(actant) = actant {
+            pending.extend(actant.guardians);
+        }
+    }
+    Ok(revision_hashes)
+}
+/// Revisions of an Actant that have not been updated yet. More than one means that
+/// agents updated it concurrently and `resolve_actant_conflict` should merge them.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ActantHeads {
+    pub latest: Record,
+    pub conflicting: Vec<Record>,
+}
+/// Walks the update graph of an Actant and picks the deepest unmerged revision as the
+/// latest one, breaking ties by action hash so that every agent resolves the same one
+#[hdk_extern]
+pub fn get_actant_heads(original_actant_hash: ActionHash) -> ExternResult<Option<ActantHeads>> {
+    let mut heads: Vec<(usize, Record)> = get_actant_revisions(original_actant_hash.clone())?
         .into_iter()
-        .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-    let latest_actant_hash = match latest_link {
-        Some(link) => ActionHash::from(link.target.clone()),
-        None => original_actant_hash.clone(),
-    };
-    get(latest_actant_hash, GetOptions::default())
+        .filter(|revision| !revision.updated)
+        .map(|revision| (revision.depth, revision.record))
+        .collect();
+    let links = get_links(original_actant_hash, LinkTypes::ActantUpdates, None)?;
+    let mut merged_revision_hashes: Vec<ActionHash> = Vec::new();
+    for link in links {
+        if let Some(tag) = ActantUpdateTag::from_tag(link.tag)? {
+            merged_revision_hashes.extend(tag.merged_revision_hashes);
+        }
+    }
+    heads.retain(|(_, record)| !merged_revision_hashes.contains(record.action_address()));
+    heads.sort_by(|(depth_a, record_a), (depth_b, record_b)| {
+        depth_b
+            .cmp(depth_a)
+            .then_with(|| record_a.action_address().cmp(record_b.action_address()))
+    });
+    let mut heads = heads.into_iter().map(|(_, record)| record);
+    Ok(heads.next().map(|latest| ActantHeads {
+        latest,
+        conflicting: heads.collect(),
+    }))
+}
+/// A revision reached by walking the update graph of an Actant from the original
+struct WalkedRevision {
+    record: Record,
+    /// Number of updates between this revision and the original
+    depth: usize,
+    updated: bool,
+}
+/// Every revision in the update graph of an Actant. Deleted revisions and the ones built
+/// on them are left out.
+fn get_actant_revisions(original_actant_hash: ActionHash) -> ExternResult<Vec<WalkedRevision>> {
+    let mut revisions: Vec<WalkedRevision> = Vec::new();
+    let mut pending = vec![(0, original_actant_hash)];
+    while let Some((depth, actant_hash)) = pending.pop() {
+        let details = match get_details(actant_hash, GetOptions::default())? {
+            Some(Details::Record(details)) => details,
+            _ => continue,
+        };
+        if !details.deletes.is_empty() {
+            continue;
+        }
+        for update in &details.updates {
+            pending.push((depth + 1, update.hashed.hash.clone()));
+        }
+        revisions.push(WalkedRevision {
+            record: details.record,
+            depth,
+            updated: !details.updates.is_empty(),
+        });
+    }
+    Ok(revisions)
 }
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UpdateActantInput {
@@ -37,7 +181,9 @@ pub struct UpdateActantInput {
     pub updated_actant: Actant,
 }
 #[hdk_extern]
-pub fn update_actant(input: UpdateActantInput) -> ExternResult<Record> {
+pub fn update_actant(mut input: UpdateActantInput) -> ExternResult<Record> {
+    cite_guardian_revisions(&mut input.updated_actant)?;
+    let previous_agents = get_actant_agents(&input.previous_actant_hash)?;
     let updated_actant_hash = update_entry(
         input.previous_actant_hash.clone(),
         &input.updated_actant,
@@ -48,6 +194,13 @@ pub fn update_actant(input: UpdateActantInput) -> ExternResult<Record> {
         LinkTypes::ActantUpdates,
         (),
     )?;
+    unlink_removed_agents(&input.original_actant_hash, &previous_agents, &input.updated_actant)?;
+    crate::invitation::invite_added_agents(
+        &input.original_actant_hash,
+        &previous_agents,
+        &updated_actant_hash,
+        &input.updated_actant,
+    )?;
     let record = get(updated_actant_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
@@ -56,7 +209,275 @@ pub fn update_actant(input: UpdateActantInput) -> ExternResult<Record> {
         )?;
     Ok(record)
 }
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResolveActantConflictInput {
+    pub original_actant_hash: ActionHash,
+    pub merged_actant: Actant,
+}
+/// Updates the latest revision with the merged content and marks the conflicting ones as merged
+#[hdk_extern]
+pub fn resolve_actant_conflict(mut input: ResolveActantConflictInput) -> ExternResult<Record> {
+    cite_guardian_revisions(&mut input.merged_actant)?;
+    let heads = get_actant_heads(input.original_actant_hash.clone())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Actant"))
+            ),
+        )?;
+    if heads.conflicting.is_empty() {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("The Actant has no conflicting revisions"))
+            ),
+        );
+    }
+    let mut previous_agents: Vec<AgentPubKey> = Vec::new();
+    for head in std::iter::once(&heads.latest).chain(heads.conflicting.iter()) {
+        for agent in get_actant_agents(head.action_address())? {
+            if !previous_agents.contains(&agent) {
+                previous_agents.push(agent);
+            }
+        }
+    }
+    let merged_actant_hash = update_entry(
+        heads.latest.action_address().clone(),
+        &input.merged_actant,
+    )?;
+    let tag: SerializedBytes = ActantUpdateTag {
+        merged_revision_hashes: heads
+            .conflicting
+            .iter()
+            .map(|record| record.action_address().clone())
+            .collect(),
+    }
+        .try_into()
+        .map_err(|e: SerializedBytesError| wasm_error!(e))?;
+    create_link(
+        input.original_actant_hash.clone(),
+        merged_actant_hash.clone(),
+        LinkTypes::ActantUpdates,
+        LinkTag::new(tag.bytes().clone()),
+    )?;
+    unlink_removed_agents(&input.original_actant_hash, &previous_agents, &input.merged_actant)?;
+    crate::invitation::invite_added_agents(
+        &input.original_actant_hash,
+        &previous_agents,
+        &merged_actant_hash,
+        &input.merged_actant,
+    )?;
+    let record = get(merged_actant_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the newly merged Actant"))
+            ),
+        )?;
+    Ok(record)
+}
+/// Latest revisions of the Actants the agent is a member of
+#[hdk_extern]
+pub fn get_actants_for_agent(agent: AgentPubKey) -> ExternResult<Vec<Record>> {
+    let links = get_links(agent.clone(), LinkTypes::AgentToActants, None)?;
+    let mut actant_hashes: Vec<ActionHash> = links
+        .into_iter()
+        .map(|link| ActionHash::from(link.target))
+        .collect();
+    actant_hashes.sort();
+    actant_hashes.dedup();
+    let mut records = Vec::new();
+    for actant_hash in actant_hashes {
+        if let Some(record) = get_actant(actant_hash)? {
+            let actant: Option<Actant> = record
+                .entry()
+                .to_app_option()
+                .map_err(|e| wasm_error!(e))?;
+            // Skip links that a concurrent membership update has not cleaned up yet
+            if actant.map_or(false, |actant| actant.agents.contains(&agent)) {
+                records.push(record);
+            }
+        }
+    }
+    Ok(records)
+}
+#[hdk_extern]
+pub fn get_my_actants(_: ()) -> ExternResult<Vec<Record>> {
+    get_actants_for_agent(agent_info()?.agent_initial_pubkey)
+}
+/// Removes the calling agent from the latest revision of the Actant
+#[hdk_extern]
+pub fn leave_actant(original_actant_hash: ActionHash) -> ExternResult<Record> {
+    let latest = get_actant(original_actant_hash.clone())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Actant"))
+            ),
+        )?;
+    let mut actant: Actant = latest
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Latest record must be an Actant"))
+            ),
+        )?;
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    actant.agents.retain(|agent| *agent != my_pub_key);
+    actant.admins.retain(|agent| *agent != my_pub_key);
+    update_actant(UpdateActantInput {
+        original_actant_hash,
+        previous_actant_hash: latest.action_address().clone(),
+        updated_actant: actant,
+    })
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetActantRoleInput {
+    pub original_actant_hash: ActionHash,
+    pub agent: AgentPubKey,
+    pub role: ActantRole,
+}
+/// Promotes an agent of the Actant to admin or demotes it to a plain member
+#[hdk_extern]
+pub fn set_actant_role(input: SetActantRoleInput) -> ExternResult<Record> {
+    let latest = get_actant(input.original_actant_hash.clone())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Actant"))
+            ),
+        )?;
+    let mut actant: Actant = latest
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Latest record must be an Actant"))
+            ),
+        )?;
+    if !actant.agents.contains(&input.agent) {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("The agent is not listed in the Actant"))
+            ),
+        );
+    }
+    actant.admins.retain(|agent| *agent != input.agent);
+    if input.role == ActantRole::Admin {
+        actant.admins.push(input.agent);
+    }
+    update_actant(UpdateActantInput {
+        original_actant_hash: input.original_actant_hash,
+        previous_actant_hash: latest.action_address().clone(),
+        updated_actant: actant,
+    })
+}
 #[hdk_extern]
 pub fn delete_actant(original_actant_hash: ActionHash) -> ExternResult<ActionHash> {
     delete_entry(original_actant_hash)
 }
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NameChange {
+    pub previous: String,
+    pub current: String,
+}
+/// Field-level changes of an Actant revision against the revision it updated
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ActantDiff {
+    pub name: Option<NameChange>,
+    pub added_agents: Vec<AgentPubKey>,
+    pub removed_agents: Vec<AgentPubKey>,
+    pub added_admins: Vec<AgentPubKey>,
+    pub removed_admins: Vec<AgentPubKey>,
+}
+impl ActantDiff {
+    pub fn between(previous: &Actant, current: &Actant) -> ActantDiff {
+        ActantDiff {
+            name: if previous.name == current.name {
+                None
+            } else {
+                Some(NameChange {
+                    previous: previous.name.clone(),
+                    current: current.name.clone(),
+                })
+            },
+            added_agents: current
+                .agents
+                .iter()
+                .filter(|agent| !previous.agents.contains(agent))
+                .cloned()
+                .collect(),
+            removed_agents: previous
+                .agents
+                .iter()
+                .filter(|agent| !current.agents.contains(agent))
+                .cloned()
+                .collect(),
+            added_admins: current
+                .admins
+                .iter()
+                .filter(|agent| !previous.admins.contains(agent))
+                .cloned()
+                .collect(),
+            removed_admins: previous
+                .admins
+                .iter()
+                .filter(|agent| !current.admins.contains(agent))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ActantRevision {
+    pub record: Record,
+    pub author: AgentPubKey,
+    pub timestamp: Timestamp,
+    /// The revision this one updated, `None` for the original Actant
+    pub previous_actant_hash: Option<ActionHash>,
+    pub diff: Option<ActantDiff>,
+}
+/// Every revision of an Actant, each one after the revision it updated. Walks the same
+/// update graph as `get_actant_heads`, so the history ends with the revisions it resolves.
+#[hdk_extern]
+pub fn get_actant_history(original_actant_hash: ActionHash) -> ExternResult<Vec<ActantRevision>> {
+    let revisions = get_actant_revisions(original_actant_hash)?;
+    let depths: HashMap<ActionHash, usize> = revisions
+        .iter()
+        .map(|revision| (revision.record.action_address().clone(), revision.depth))
+        .collect();
+    let actants: HashMap<ActionHash, (Record, Actant)> = revisions
+        .into_iter()
+        .filter_map(|revision| {
+            let actant: Actant = revision.record.entry().to_app_option().ok()??;
+            Some((revision.record.action_address().clone(), (revision.record, actant)))
+        })
+        .collect();
+    let previous_hash = |record: &Record| match record.action() {
+        Action::Update(update) => Some(update.original_action_address.clone()),
+        _ => None,
+    };
+    let mut history: Vec<ActantRevision> = actants
+        .values()
+        .map(|(record, actant)| {
+            let previous_actant_hash = previous_hash(record);
+            let diff = previous_actant_hash
+                .as_ref()
+                .and_then(|hash| actants.get(hash))
+                .map(|(_, previous_actant)| ActantDiff::between(previous_actant, actant));
+            ActantRevision {
+                record: record.clone(),
+                author: record.action().author().clone(),
+                timestamp: record.action().timestamp(),
+                previous_actant_hash,
+                diff,
+            }
+        })
+        .collect();
+    history.sort_by_key(|revision| {
+        (
+            depths.get(revision.record.action_address()).copied().unwrap_or_default(),
+            revision.timestamp,
+            revision.record.action_address().clone(),
+        )
+    });
+    Ok(history)
+}
@@ -1,9 +1,21 @@
 use hdk::prelude::*;
 use stewardship_integrity::*;
+use crate::pagination::{get_latest, get_page, Page, PageInput};
+use crate::time_shard::{get_time_shard_links, TimeRange};
+/// The most recently created Actants, newest first and at most `MAX_LISTING_SIZE` of them.
+/// Older ones are reached through `get_all_actants_page`
 #[hdk_extern]
 pub fn get_all_actants(_: ()) -> ExternResult<Vec<Record>> {
-    let path = Path::from("all_actants");
-    let links = get_links(path.path_entry_hash()?, LinkTypes::AllActants, None)?;
+    get_latest("all_actants", LinkTypes::AllActantsShards, LinkTypes::AllActants)
+}
+#[hdk_extern]
+pub fn get_actants_in_range(range: TimeRange) -> ExternResult<Vec<Record>> {
+    let links = get_time_shard_links(
+        "all_actants",
+        LinkTypes::AllActantsShards,
+        LinkTypes::AllActants,
+        range.clone(),
+    )?;
     let get_input: Vec<GetInput> = links
         .into_iter()
         .map(|link| GetInput::new(
@@ -12,6 +24,17 @@ pub fn get_all_actants(_: ()) -> ExternResult<Vec<Record>> {
         ))
         .collect();
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
-    let records: Vec<Record> = records.into_iter().filter_map(|r| r).collect();
+    let records: Vec<Record> = records
+        .into_iter()
+        .filter_map(|r| r)
+        .filter(|record| {
+            record.action().timestamp() >= range.from
+                && record.action().timestamp() <= range.to
+        })
+        .collect();
     Ok(records)
 }
+#[hdk_extern]
+pub fn get_all_actants_page(input: PageInput) -> ExternResult<Page> {
+    get_page("all_actants", LinkTypes::AllActantsShards, LinkTypes::AllActants, input)
+}
@@ -1,9 +1,36 @@
 use hdk::prelude::*;
 use stewardship_integrity::*;
+use crate::pagination::{get_latest, get_page, Page, PageInput};
+use crate::time_shard::{get_time_shard_links, TimeRange};
+/// The most recently created clauses, newest first and at most `MAX_LISTING_SIZE` of them,
+/// or only those of them currently in `status` when given. Older ones are reached through
+/// `get_all_clauses_page`
 #[hdk_extern]
-pub fn get_all_clauses(_: ()) -> ExternResult<Vec<Record>> {
-    let path = Path::from("all_clauses");
-    let links = get_links(path.path_entry_hash()?, LinkTypes::AllClauses, None)?;
+pub fn get_all_clauses(status: Option<ClauseStatus>) -> ExternResult<Vec<Record>> {
+    let records = get_latest("all_clauses", LinkTypes::AllClausesShards, LinkTypes::AllClauses)?;
+    let status = match status {
+        Some(status) => status,
+        None => return Ok(records),
+    };
+    let states = crate::clause_transition::get_clause_states(
+        records.iter().map(|record| record.action_address().clone()).collect(),
+    )?;
+    let filtered: Vec<Record> = records
+        .into_iter()
+        .zip(states)
+        .filter(|(_, state)| state.status == status)
+        .map(|(record, _)| record)
+        .collect();
+    Ok(filtered)
+}
+#[hdk_extern]
+pub fn get_clauses_in_range(range: TimeRange) -> ExternResult<Vec<Record>> {
+    let links = get_time_shard_links(
+        "all_clauses",
+        LinkTypes::AllClausesShards,
+        LinkTypes::AllClauses,
+        range.clone(),
+    )?;
     let get_input: Vec<GetInput> = links
         .into_iter()
         .map(|link| GetInput::new(
@@ -12,6 +39,17 @@ pub fn get_all_clauses(_: ()) -> ExternResult<Vec<Record>> {
         ))
         .collect();
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
-    let records: Vec<Record> = records.into_iter().filter_map(|r| r).collect();
+    let records: Vec<Record> = records
+        .into_iter()
+        .filter_map(|r| r)
+        .filter(|record| {
+            record.action().timestamp() >= range.from
+                && record.action().timestamp() <= range.to
+        })
+        .collect();
     Ok(records)
 }
+#[hdk_extern]
+pub fn get_all_clauses_page(input: PageInput) -> ExternResult<Page> {
+    get_page("all_clauses", LinkTypes::AllClausesShards, LinkTypes::AllClauses, input)
+}
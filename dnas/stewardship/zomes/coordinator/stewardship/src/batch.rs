@@ -0,0 +1,29 @@
+use hdk::prelude::*;
+use stewardship_integrity::*;
+/// Gets the links of `link_type` from every base in one host call, in the order of `bases`
+pub fn get_links_batch(
+    bases: Vec<ActionHash>,
+    link_type: LinkTypes,
+) -> ExternResult<Vec<Vec<Link>>> {
+    let link_type = link_type.try_into_filter()?;
+    let get_links_input: Vec<GetLinksInput> = bases
+        .into_iter()
+        .map(|base| GetLinksInput::new(base.into(), link_type.clone(), None))
+        .collect();
+    HDK.with(|hdk| hdk.borrow().get_links(get_links_input))
+}
+/// Gets the records at `hashes` in one host call, in the order of `hashes`
+pub fn get_records_batch(hashes: Vec<ActionHash>) -> ExternResult<Vec<Option<Record>>> {
+    let get_input: Vec<GetInput> = hashes
+        .into_iter()
+        .map(|hash| GetInput::new(hash.into(), GetOptions::default()))
+        .collect();
+    HDK.with(|hdk| hdk.borrow().get(get_input))
+}
+/// The earliest of the links by timestamp, breaking ties by target so that every agent
+/// picks the same one
+pub fn earliest_link(links: &[Link]) -> Option<&Link> {
+    links
+        .iter()
+        .min_by(|a, b| a.timestamp.cmp(&b.timestamp).then_with(|| a.target.cmp(&b.target)))
+}
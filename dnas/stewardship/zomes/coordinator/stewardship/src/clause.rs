@@ -4,10 +4,20 @@ use stewardship_integrity::*;
 pub fn create_clause(clause: Clause) -> ExternResult<Record> {
     let clause_hash = create_entry(&EntryTypes::Clause(clause.clone()))?;
     for base in clause.responsibilty_holders.clone() {
-        create_link(base, clause_hash.clone(), LinkTypes::ActantToClauses, ())?;
+        create_link(
+            base,
+            clause_hash.clone(),
+            LinkTypes::ActantToClauses,
+            ClauseRole::ResponsibilityHolder.tag(),
+        )?;
     }
     for base in clause.right_holders.clone() {
-        create_link(base, clause_hash.clone(), LinkTypes::ActantToClauses, ())?;
+        create_link(
+            base,
+            clause_hash.clone(),
+            LinkTypes::ActantToClauses,
+            ClauseRole::RightHolder.tag(),
+        )?;
     }
     let record = get(clause_hash.clone(), GetOptions::default())?
         .ok_or(
@@ -15,26 +25,102 @@ pub fn create_clause(clause: Clause) -> ExternResult<Record> {
                 WasmErrorInner::Guest(String::from("Could not find the newly created Clause"))
             ),
         )?;
-    let path = Path::from("all_clauses");
-    create_link(
-        path.path_entry_hash()?,
-        clause_hash.clone(),
+    crate::time_shard::link_to_time_shard(
+        "all_clauses",
+        LinkTypes::AllClausesShards,
         LinkTypes::AllClauses,
-        (),
+        &record,
     )?;
     Ok(record)
 }
 #[hdk_extern]
-pub fn get_clause(clause_hash: ActionHash) -> ExternResult<Option<Record>> {
-    get(clause_hash, GetOptions::default())
+pub fn get_clause(original_clause_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let history = get_clause_history(original_clause_hash)?;
+    Ok(history.last().cloned())
 }
 #[hdk_extern]
-pub fn delete_clause(original_clause_hash: ActionHash) -> ExternResult<ActionHash> {
-    delete_entry(original_clause_hash)
+pub fn get_clause_history(original_clause_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let original_record = match get(original_clause_hash.clone(), GetOptions::default())? {
+        Some(record) => record,
+        None => return Ok(vec![]),
+    };
+    let links = get_links(original_clause_hash, LinkTypes::ClauseUpdates, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(
+            ActionHash::from(link.target).into(),
+            GetOptions::default(),
+        ))
+        .collect();
+    let accepted_records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+    let mut history = vec![original_record];
+    loop {
+        let previous_hash = history[history.len() - 1].action_address().clone();
+        // If two amendments of the same version were both accepted, the one with the lowest
+        // action hash wins: timestamps are set by the proposer and could be backdated
+        let next_record = accepted_records
+            .iter()
+            .filter(|record| match record.action() {
+                Action::Update(update) => update.original_action_address == previous_hash,
+                _ => false,
+            })
+            .min_by_key(|record| record.action_address().clone());
+        match next_record {
+            Some(record) => history.push(record.clone()),
+            None => break,
+        }
+    }
+    Ok(history)
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateClauseInput {
+    pub original_clause_hash: ActionHash,
+    pub previous_clause_hash: ActionHash,
+    pub updated_clause: Clause,
+}
+/// Fails unless `clause_hash` is the current accepted version of the clause
+pub fn must_be_current_clause(
+    original_clause_hash: ActionHash,
+    clause_hash: &ActionHash,
+) -> ExternResult<()> {
+    let current = get_clause(original_clause_hash)?;
+    if current.as_ref().map(|record| record.action_address()) != Some(clause_hash) {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Amendments must be made to the current version of the Clause"))
+            ),
+        );
+    }
+    Ok(())
 }
 #[hdk_extern]
-pub fn get_clauses_for_actant(actant_hash: ActionHash) -> ExternResult<Vec<Record>> {
-    let links = get_links(actant_hash, LinkTypes::ActantToClauses, None)?;
+pub fn propose_clause_amendment(input: UpdateClauseInput) -> ExternResult<Record> {
+    must_be_current_clause(input.original_clause_hash.clone(), &input.previous_clause_hash)?;
+    let clause_revision_hash = update_entry(
+        input.previous_clause_hash.clone(),
+        &input.updated_clause,
+    )?;
+    create_link(
+        input.original_clause_hash.clone(),
+        clause_revision_hash.clone(),
+        LinkTypes::ClauseProposals,
+        (),
+    )?;
+    let record = get(clause_revision_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the newly proposed Clause amendment"))
+            ),
+        )?;
+    Ok(record)
+}
+#[hdk_extern]
+pub fn get_clause_proposals(original_clause_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let links = get_links(original_clause_hash, LinkTypes::ClauseProposals, None)?;
     let get_input: Vec<GetInput> = links
         .into_iter()
         .map(|link| GetInput::new(
@@ -49,3 +135,66 @@ pub fn get_clauses_for_actant(actant_hash: ActionHash) -> ExternResult<Vec<Recor
         .collect();
     Ok(records)
 }
+#[hdk_extern]
+pub fn delete_clause(original_clause_hash: ActionHash) -> ExternResult<ActionHash> {
+    delete_entry(original_clause_hash)
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetClausesForActantInput {
+    pub actant_hash: ActionHash,
+    /// Only return clauses where the Actant holds this role, or both roles if `None`
+    pub role: Option<ClauseRole>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClauseForActant {
+    pub role: ClauseRole,
+    pub record: Record,
+}
+#[hdk_extern]
+pub fn get_clauses_for_actant(
+    input: GetClausesForActantInput,
+) -> ExternResult<Vec<ClauseForActant>> {
+    let links = get_links(
+        input.actant_hash,
+        LinkTypes::ActantToClauses,
+        input.role.map(|role| role.tag()),
+    )?;
+    let roles: Vec<Option<ClauseRole>> = links
+        .iter()
+        .map(|link| ClauseRole::from_tag(&link.tag))
+        .collect();
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(
+            ActionHash::from(link.target).into(),
+            GetOptions::default(),
+        ))
+        .collect();
+    let clauses: Vec<ClauseForActant> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .zip(roles)
+        .filter_map(|(record, role)| Some(ClauseForActant {
+            role: role?,
+            record: record?,
+        }))
+        .collect();
+    Ok(clauses)
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddClauseForActantInput {
+    pub base_actant_hash: ActionHash,
+    pub target_clause_hash: ActionHash,
+    pub role: ClauseRole,
+}
+/// Links a clause to one of its holders, for when the link made by `create_clause` is missing
+#[hdk_extern]
+pub fn add_clause_for_actant(input: AddClauseForActantInput) -> ExternResult<()> {
+    create_link(
+        input.base_actant_hash,
+        input.target_clause_hash,
+        LinkTypes::ActantToClauses,
+        input.role.tag(),
+    )?;
+    Ok(())
+}
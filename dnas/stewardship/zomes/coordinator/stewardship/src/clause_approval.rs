@@ -0,0 +1,172 @@
+use hdk::prelude::*;
+use stewardship_integrity::*;
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ApproveClauseAmendmentInput {
+    pub original_clause_hash: ActionHash,
+    pub clause_revision_hash: ActionHash,
+    pub actant_hash: ActionHash,
+}
+#[hdk_extern]
+pub fn approve_clause_amendment(input: ApproveClauseAmendmentInput) -> ExternResult<Record> {
+    let clause_approval = ClauseApproval {
+        clause_revision_hash: input.clause_revision_hash.clone(),
+        actant_hash: input.actant_hash.clone(),
+        actant_revision_hashes: crate::actant::get_latest_revision_hashes(&[input.actant_hash.clone()])?,
+    };
+    let clause_approval_hash = create_entry(
+        &EntryTypes::ClauseApproval(clause_approval.clone()),
+    )?;
+    create_link(
+        input.clause_revision_hash.clone(),
+        clause_approval_hash.clone(),
+        LinkTypes::ClauseApprovals,
+        (),
+    )?;
+    let record = get(clause_approval_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the newly created ClauseApproval"))
+            ),
+        )?;
+    finalize_clause_amendment(FinalizeClauseAmendmentInput {
+        original_clause_hash: input.original_clause_hash,
+        clause_revision_hash: input.clause_revision_hash,
+    })?;
+    Ok(record)
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddApprovalForClauseAmendmentInput {
+    pub base_clause_revision_hash: ActionHash,
+    pub target_clause_approval_hash: ActionHash,
+}
+/// Links an approval to its amendment, for when the link made by `approve_clause_amendment`
+/// is missing
+#[hdk_extern]
+pub fn add_approval_for_clause_amendment(
+    input: AddApprovalForClauseAmendmentInput,
+) -> ExternResult<()> {
+    create_link(
+        input.base_clause_revision_hash,
+        input.target_clause_approval_hash,
+        LinkTypes::ClauseApprovals,
+        (),
+    )?;
+    Ok(())
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FinalizeClauseAmendmentInput {
+    pub original_clause_hash: ActionHash,
+    pub clause_revision_hash: ActionHash,
+}
+/// Accepts the amendment if every responsibility holder has approved it, returning whether
+/// it is accepted. Approvals may reach the caller late, so any agent can call this again
+/// once they have, and calling it on an accepted amendment does nothing
+#[hdk_extern]
+pub fn finalize_clause_amendment(input: FinalizeClauseAmendmentInput) -> ExternResult<bool> {
+    let accepted_links = get_links(
+        input.original_clause_hash.clone(),
+        LinkTypes::ClauseUpdates,
+        None,
+    )?;
+    if accepted_links
+        .iter()
+        .any(|link| ActionHash::from(link.target.clone()) == input.clause_revision_hash)
+    {
+        return Ok(true);
+    }
+    let revision_record = get(input.clause_revision_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Clause amendment"))
+            ),
+        )?;
+    let previous_clause_hash = match revision_record.action() {
+        Action::Update(update) => update.original_action_address.clone(),
+        _ => {
+            return Err(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Only Clause amendments can be approved"))
+                ),
+            );
+        }
+    };
+    crate::clause::must_be_current_clause(
+        input.original_clause_hash.clone(),
+        &previous_clause_hash,
+    )?;
+    let previous_acceptance_hash = accepted_links
+        .iter()
+        .find(|link| ActionHash::from(link.target.clone()) == previous_clause_hash)
+        .map(|link| link.create_link_hash.clone());
+    let previous_clause: Clause = get(previous_clause_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the amended Clause"))
+            ),
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Amended record must be a Clause"))
+            ),
+        )?;
+    let mut approving_actants: Vec<ActionHash> = Vec::new();
+    let mut approval_hashes: Vec<ActionHash> = Vec::new();
+    for approval_record in get_approvals_for_clause_revision(input.clause_revision_hash.clone())? {
+        let approval: Option<ClauseApproval> = approval_record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        if let Some(approval) = approval {
+            if approval.clause_revision_hash == input.clause_revision_hash {
+                approving_actants.push(approval.actant_hash);
+                approval_hashes.push(approval_record.action_address().clone());
+            }
+        }
+    }
+    let is_approved = previous_clause
+        .responsibilty_holders
+        .iter()
+        .all(|actant_hash| approving_actants.contains(actant_hash));
+    if !is_approved {
+        return Ok(false);
+    }
+    let tag: SerializedBytes = ClauseUpdateTag {
+        approval_hashes,
+        previous_acceptance_hash,
+    }
+    .try_into()
+    .map_err(|e: SerializedBytesError| wasm_error!(e))?;
+    create_link(
+        input.original_clause_hash,
+        input.clause_revision_hash,
+        LinkTypes::ClauseUpdates,
+        LinkTag::new(tag.bytes().clone()),
+    )?;
+    Ok(true)
+}
+#[hdk_extern]
+pub fn get_clause_approval(clause_approval_hash: ActionHash) -> ExternResult<Option<Record>> {
+    get(clause_approval_hash, GetOptions::default())
+}
+#[hdk_extern]
+pub fn get_approvals_for_clause_revision(
+    clause_revision_hash: ActionHash,
+) -> ExternResult<Vec<Record>> {
+    let links = get_links(clause_revision_hash, LinkTypes::ClauseApprovals, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(
+            ActionHash::from(link.target).into(),
+            GetOptions::default(),
+        ))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+    Ok(records)
+}
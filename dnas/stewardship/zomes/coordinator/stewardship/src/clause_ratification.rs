@@ -0,0 +1,129 @@
+use hdk::prelude::*;
+use stewardship_integrity::*;
+use crate::batch::earliest_link;
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RequestClauseRatificationInput {
+    pub clause_hash: ActionHash,
+    /// One agent for each holder Actant of the clause, including the caller
+    pub signing_agents: Vec<AgentPubKey>,
+    pub session_length_ms: u64,
+}
+/// Builds the countersigning request for a ratification session, citing the latest
+/// revisions of the holders. Every signing agent must accept it with
+/// `accept_clause_ratification` before the session expires.
+#[hdk_extern]
+pub fn request_clause_ratification(
+    input: RequestClauseRatificationInput,
+) -> ExternResult<PreflightRequest> {
+    let clause: Clause = get(input.clause_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Clause"))
+            ),
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Record must be a Clause"))
+            ),
+        )?;
+    let holders: Vec<ActionHash> = clause
+        .right_holders
+        .into_iter()
+        .chain(clause.responsibilty_holders)
+        .collect();
+    let revisions = RatificationRevisions {
+        actant_revision_hashes: crate::actant::get_latest_revision_hashes(&holders)?,
+    };
+    let ratification = ClauseRatification {
+        clause_hash: input.clause_hash,
+    };
+    let entry_type: EntryType = UnitEntryTypes::ClauseRatification.try_into()?;
+    PreflightRequest::try_new(
+        hash_entry(&ratification)?,
+        input.signing_agents.into_iter().map(|agent| (agent, vec![])).collect(),
+        Vec::new(),
+        0,
+        false,
+        session_times_from_millis(input.session_length_ms)?,
+        ActionBase::Create(CreateBase::new(entry_type)),
+        PreflightBytes(encode(&revisions).map_err(|e| wasm_error!(e))?),
+    )
+    .map_err(|e| wasm_error!(WasmErrorInner::Guest(e.to_string())))
+}
+#[hdk_extern]
+pub fn accept_clause_ratification(
+    preflight_request: PreflightRequest,
+) -> ExternResult<PreflightRequestAcceptance> {
+    accept_countersigning_preflight_request(preflight_request)
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CommitClauseRatificationInput {
+    pub clause_hash: ActionHash,
+    pub responses: Vec<PreflightResponse>,
+}
+/// Commits the countersigned ratification. Each signing agent calls this with the
+/// responses of all of them; the session completes once every agent has committed.
+#[hdk_extern]
+pub fn commit_clause_ratification(input: CommitClauseRatificationInput) -> ExternResult<ActionHash> {
+    let session_data = CounterSigningSessionData::try_from_responses(input.responses, vec![])
+        .map_err(|e| wasm_error!(WasmErrorInner::Guest(e.to_string())))?;
+    let ratification = ClauseRatification {
+        clause_hash: input.clause_hash,
+    };
+    let entry_def_index: ScopedEntryDefIndex = UnitEntryTypes::ClauseRatification.try_into()?;
+    HDK.with(|hdk| {
+        hdk.borrow()
+            .create(
+                CreateInput::new(
+                    entry_def_index,
+                    EntryVisibility::Public,
+                    Entry::CounterSign(Box::new(session_data), ratification.try_into()?),
+                    ChainTopOrdering::Strict,
+                ),
+            )
+    })
+}
+/// The ratification of the clause on the caller's source chain, if it signed one
+pub fn get_my_clause_ratification(clause_hash: &ActionHash) -> ExternResult<Option<ActionHash>> {
+    let my_ratifications = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::ClauseRatification.try_into()?)
+            .include_entries(true),
+    )?;
+    for record in my_ratifications {
+        if let Some(Entry::CounterSign(_, app_entry_bytes)) = record.entry().as_option() {
+            let ratification = ClauseRatification::try_from(app_entry_bytes.clone().into_sb())
+                .map_err(|e| wasm_error!(e))?;
+            if ratification.clause_hash == *clause_hash {
+                return Ok(Some(record.action_address().clone()));
+            }
+        }
+    }
+    Ok(None)
+}
+/// Links the caller's ratification from the clause. The countersigned action must be the
+/// only write of its session, so this is called once the session has completed and the
+/// caller's chain is unlocked.
+#[hdk_extern]
+pub fn link_clause_ratification(clause_hash: ActionHash) -> ExternResult<ActionHash> {
+    let ratification_hash = get_my_clause_ratification(&clause_hash)?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("You have not ratified this Clause"))
+            ),
+        )?;
+    create_link(clause_hash, ratification_hash, LinkTypes::ClauseToRatifications, ())
+}
+/// The countersigned record proving that every holder agreed to the clause, once one of
+/// its signers has linked it
+#[hdk_extern]
+pub fn get_clause_ratification(clause_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let links = get_links(clause_hash, LinkTypes::ClauseToRatifications, None)?;
+    match earliest_link(&links) {
+        Some(link) => get(ActionHash::from(link.target.clone()), GetOptions::default()),
+        None => Ok(None),
+    }
+}
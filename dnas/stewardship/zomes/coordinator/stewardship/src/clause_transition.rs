@@ -0,0 +1,156 @@
+use hdk::prelude::*;
+use stewardship_integrity::*;
+use crate::batch::{get_links_batch, get_records_batch};
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClauseState {
+    pub status: ClauseStatus,
+    /// The transition that moved the clause into `status`, `None` for drafts
+    pub transition_hash: Option<ActionHash>,
+}
+/// Transitions of a clause in the order they were applied. When several transitions
+/// follow the same one, the one with the lowest action hash wins and the others are
+/// ignored: timestamps are set by their authors and could be backdated.
+#[hdk_extern]
+pub fn get_clause_transitions(clause_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let links = get_links(clause_hash, LinkTypes::ClauseToTransitions, None)?;
+    let records = get_records_batch(
+        links.into_iter().map(|link| ActionHash::from(link.target)).collect(),
+    )?;
+    chain_transitions(records.into_iter().flatten().collect())
+}
+/// Orders the transition records of a clause into the chain they form
+fn chain_transitions(records: Vec<Record>) -> ExternResult<Vec<Record>> {
+    let mut transitions: Vec<(Record, ClauseTransition)> = Vec::new();
+    for record in records {
+        let transition: Option<ClauseTransition> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        if let Some(transition) = transition {
+            transitions.push((record, transition));
+        }
+    }
+    transitions.sort_by(|(a, _), (b, _)| a.action_address().cmp(b.action_address()));
+    let mut chain: Vec<Record> = Vec::new();
+    let mut current: Option<ActionHash> = None;
+    while let Some((record, _)) = transitions
+        .iter()
+        .find(|(_, transition)| transition.previous_transition_hash == current)
+    {
+        current = Some(record.action_address().clone());
+        chain.push(record.clone());
+    }
+    Ok(chain)
+}
+/// The state reached by the last transition of a chain
+fn state_from_transitions(transitions: &[Record]) -> ExternResult<ClauseState> {
+    let last = match transitions.last() {
+        Some(last) => last,
+        None => {
+            return Ok(ClauseState {
+                status: ClauseStatus::Draft,
+                transition_hash: None,
+            });
+        }
+    };
+    let transition: ClauseTransition = last
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Record must be a ClauseTransition"))
+            ),
+        )?;
+    Ok(ClauseState {
+        status: transition.to,
+        transition_hash: Some(last.action_address().clone()),
+    })
+}
+#[hdk_extern]
+pub fn get_clause_state(clause_hash: ActionHash) -> ExternResult<ClauseState> {
+    state_from_transitions(&get_clause_transitions(clause_hash)?)
+}
+/// States of the clauses, in the order of `clause_hashes`, with their transitions fetched
+/// in one batch of link and one batch of record calls
+pub fn get_clause_states(clause_hashes: Vec<ActionHash>) -> ExternResult<Vec<ClauseState>> {
+    let links = get_links_batch(clause_hashes, LinkTypes::ClauseToTransitions)?;
+    let records = get_records_batch(
+        links
+            .iter()
+            .flatten()
+            .map(|link| ActionHash::from(link.target.clone()))
+            .collect(),
+    )?;
+    let mut records = records.into_iter();
+    let mut states = Vec::new();
+    for clause_links in links {
+        let clause_records: Vec<Record> = records
+            .by_ref()
+            .take(clause_links.len())
+            .flatten()
+            .collect();
+        states.push(state_from_transitions(&chain_transitions(clause_records)?)?);
+    }
+    Ok(states)
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TransitionClauseInput {
+    pub clause_hash: ActionHash,
+    pub to: ClauseStatus,
+}
+/// Moves the clause from its current status to `to`. Activating a proposed clause attaches
+/// the caller's ratification of it, if any.
+#[hdk_extern]
+pub fn transition_clause(input: TransitionClauseInput) -> ExternResult<Record> {
+    let state = get_clause_state(input.clause_hash.clone())?;
+    let ratification_hash = if state.status == ClauseStatus::Proposed
+        && input.to == ClauseStatus::Active
+    {
+        crate::clause_ratification::get_my_clause_ratification(&input.clause_hash)?
+    } else {
+        None
+    };
+    let clause: Clause = get(input.clause_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Clause"))
+            ),
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Record must be a Clause"))
+            ),
+        )?;
+    let holders: Vec<ActionHash> = clause
+        .right_holders
+        .into_iter()
+        .chain(clause.responsibilty_holders)
+        .collect();
+    let transition_hash = create_entry(
+        &EntryTypes::ClauseTransition(ClauseTransition {
+            clause_hash: input.clause_hash.clone(),
+            previous_transition_hash: state.transition_hash,
+            from: state.status,
+            to: input.to,
+            ratification_hash,
+            actant_revision_hashes: crate::actant::get_latest_revision_hashes(&holders)?,
+        }),
+    )?;
+    create_link(
+        input.clause_hash,
+        transition_hash.clone(),
+        LinkTypes::ClauseToTransitions,
+        (),
+    )?;
+    let record = get(transition_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the newly created ClauseTransition"))
+            ),
+        )?;
+    Ok(record)
+}
@@ -0,0 +1,110 @@
+use hdk::prelude::*;
+use stewardship_integrity::*;
+use crate::clause::{get_clauses_for_actant, GetClausesForActantInput};
+use crate::endorsement::get_endorsements_for_reports;
+use crate::report::get_reports_for_clauses;
+use crate::report_status::{status_from_endorsements, VerificationStatus};
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ComplianceStatus {
+    /// No report has been filed against the clause
+    Unreported,
+    /// Reports have been filed but none of them is verified yet
+    Pending,
+    /// At least one report is verified and none is disputed or a verified breach
+    Compliant,
+    /// A right holder disputes at least one of the reports
+    Disputed,
+    /// A breach report has been verified
+    Breached,
+}
+/// How an Actant is meeting one of the clauses it is responsible for. Only the Actant's own
+/// reports count, and retracted ones are left out of every figure.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClauseCompliance {
+    pub clause_hash: ActionHash,
+    pub report_count: u32,
+    pub verified_report_count: u32,
+    pub latest_report_at: Option<Timestamp>,
+    pub status: ComplianceStatus,
+}
+/// Summarises the reports filed against each clause the Actant is a responsibility holder
+/// of. Clauses, reports and endorsements are each fetched for all clauses at once, so the
+/// number of DHT calls does not grow with the number of clauses or reports.
+#[hdk_extern]
+pub fn get_compliance_summary(actant_hash: ActionHash) -> ExternResult<Vec<ClauseCompliance>> {
+    let clauses = get_clauses_for_actant(GetClausesForActantInput {
+        actant_hash: actant_hash.clone(),
+        role: Some(ClauseRole::ResponsibilityHolder),
+    })?;
+    let mut decoded_clauses: Vec<(ActionHash, Clause)> = Vec::new();
+    for clause in clauses {
+        let decoded: Option<Clause> = clause
+            .record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        if let Some(decoded) = decoded {
+            decoded_clauses.push((clause.record.action_address().clone(), decoded));
+        }
+    }
+    let reports_for_clauses = get_reports_for_clauses(
+        decoded_clauses.iter().map(|(clause_hash, _)| clause_hash.clone()).collect(),
+    )?;
+    let reports_for_clauses: Vec<_> = reports_for_clauses
+        .into_iter()
+        .map(|reports| {
+            reports
+                .into_iter()
+                .filter(|report| !report.retracted && report.report.actant_hash == actant_hash)
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let mut endorsements_for_reports = get_endorsements_for_reports(
+            reports_for_clauses
+                .iter()
+                .flatten()
+                .map(|report| report.record.action_address().clone())
+                .collect(),
+            false,
+        )?
+        .into_iter();
+    let mut summary = Vec::new();
+    for ((clause_hash, clause), reports) in decoded_clauses.into_iter().zip(reports_for_clauses) {
+        let mut verified_report_count = 0;
+        let mut is_disputed = false;
+        let mut is_breached = false;
+        for report in reports.iter() {
+            let endorsements = endorsements_for_reports.next().unwrap_or_default();
+            match status_from_endorsements(&clause, &endorsements)?.status {
+                VerificationStatus::Verified => {
+                    verified_report_count += 1;
+                    is_breached |= report.report.report_type == ReportType::Breach;
+                }
+                VerificationStatus::Disputed => is_disputed = true,
+                VerificationStatus::Pending => {}
+            }
+        }
+        let status = if is_breached {
+            ComplianceStatus::Breached
+        } else if is_disputed {
+            ComplianceStatus::Disputed
+        } else if verified_report_count > 0 {
+            ComplianceStatus::Compliant
+        } else if !reports.is_empty() {
+            ComplianceStatus::Pending
+        } else {
+            ComplianceStatus::Unreported
+        };
+        summary.push(ClauseCompliance {
+            clause_hash,
+            report_count: reports.len() as u32,
+            verified_report_count,
+            latest_report_at: reports
+                .iter()
+                .map(|report| report.record.action().timestamp())
+                .max(),
+            status,
+        });
+    }
+    Ok(summary)
+}
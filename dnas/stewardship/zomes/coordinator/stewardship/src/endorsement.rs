@@ -1,7 +1,41 @@
 use hdk::prelude::*;
 use stewardship_integrity::*;
+use crate::batch::{get_links_batch, get_records_batch};
 #[hdk_extern]
-pub fn create_endorsement(endorsement: Endorsement) -> ExternResult<Record> {
+pub fn create_endorsement(mut endorsement: Endorsement) -> ExternResult<Record> {
+    if endorsement.actant_hash.is_none() {
+        endorsement.actant_hash = Some(get_my_right_holder(&endorsement.report_hash)?);
+    }
+    if endorsement.actant_revision_hashes.is_empty() {
+        if let Some(actant_hash) = &endorsement.actant_hash {
+            endorsement.actant_revision_hashes = crate::actant::get_latest_revision_hashes(
+                &[actant_hash.clone()],
+            )?;
+        }
+    }
+    let my_revoked_endorsements = get_my_revoked_endorsements()?;
+    let my_endorsements = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::Endorsement.try_into()?)
+            .include_entries(true),
+    )?;
+    for record in my_endorsements {
+        let my_endorsement: Option<Endorsement> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        if let Some(my_endorsement) = my_endorsement {
+            if my_endorsement.report_hash == endorsement.report_hash
+                && !my_revoked_endorsements.contains(record.action_address())
+            {
+                return Err(
+                    wasm_error!(
+                        WasmErrorInner::Guest(String::from("You have already endorsed this Report"))
+                    ),
+                );
+            }
+        }
+    }
     let endorsement_hash = create_entry(&EntryTypes::Endorsement(endorsement.clone()))?;
     create_link(
         endorsement.report_hash.clone(),
@@ -17,26 +51,244 @@ pub fn create_endorsement(endorsement: Endorsement) -> ExternResult<Record> {
         )?;
     Ok(record)
 }
+/// The first right holder of the report's clause that the caller can endorse for
+fn get_my_right_holder(report_hash: &ActionHash) -> ExternResult<ActionHash> {
+    let report: Report = get(report_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Could not find the Report")))
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Record must be a Report")))
+        )?;
+    let clause: Clause = get(report.clause_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Could not find the Clause")))
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Record must be a Clause")))
+        )?;
+    let agent_info = agent_info()?;
+    for right_holder in clause.right_holders {
+        let revision_hashes = crate::actant::get_latest_revision_hashes(&[right_holder.clone()])?;
+        if is_authorised_for_any_actant(
+            &[right_holder.clone()],
+            &revision_hashes,
+            &agent_info.agent_initial_pubkey,
+            &agent_info.chain_head.0,
+        )? {
+            return Ok(right_holder);
+        }
+    }
+    Err(
+        wasm_error!(
+            WasmErrorInner::Guest(String::from("You cannot act for any right holder of the clause"))
+        ),
+    )
+}
 #[hdk_extern]
 pub fn get_endorsement(endorsement_hash: ActionHash) -> ExternResult<Option<Record>> {
     get(endorsement_hash, GetOptions::default())
 }
+fn get_my_revoked_endorsements() -> ExternResult<Vec<ActionHash>> {
+    let my_revocations = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::EndorsementRevocation.try_into()?)
+            .include_entries(true),
+    )?;
+    let mut revoked = Vec::new();
+    for record in my_revocations {
+        let revocation: Option<EndorsementRevocation> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        if let Some(revocation) = revocation {
+            revoked.push(revocation.endorsement_hash);
+        }
+    }
+    Ok(revoked)
+}
+/// An endorsement along with its revocation, if it has been revoked
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EndorsementWithRevocation {
+    pub record: Record,
+    pub revocation: Option<Record>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetEndorsementsForReportInput {
+    pub report_hash: ActionHash,
+    #[serde(default)]
+    pub include_revoked: bool,
+}
+/// Endorsements of the report that have not been revoked, or all of them when
+/// `include_revoked` is set
 #[hdk_extern]
 pub fn get_endorsements_for_report(
-    report_hash: ActionHash,
-) -> ExternResult<Vec<Record>> {
-    let links = get_links(report_hash, LinkTypes::ReportToEndorsements, None)?;
-    let get_input: Vec<GetInput> = links
-        .into_iter()
-        .map(|link| GetInput::new(
-            ActionHash::from(link.target).into(),
-            GetOptions::default(),
-        ))
+    input: GetEndorsementsForReportInput,
+) -> ExternResult<Vec<EndorsementWithRevocation>> {
+    Ok(
+        get_endorsements_for_reports(vec![input.report_hash], input.include_revoked)?
+            .pop()
+            .unwrap_or_default(),
+    )
+}
+/// The endorsements of each report, in the order of `report_hashes`, fetched with the same
+/// number of DHT calls however many reports and endorsements there are
+pub fn get_endorsements_for_reports(
+    report_hashes: Vec<ActionHash>,
+    include_revoked: bool,
+) -> ExternResult<Vec<Vec<EndorsementWithRevocation>>> {
+    let links = get_links_batch(report_hashes, LinkTypes::ReportToEndorsements)?;
+    let records: Vec<Vec<Record>> = {
+        let endorsement_counts: Vec<usize> = links.iter().map(|links| links.len()).collect();
+        let mut records = get_records_batch(
+                links
+                    .into_iter()
+                    .flatten()
+                    .map(|link| ActionHash::from(link.target))
+                    .collect(),
+            )?
+            .into_iter();
+        endorsement_counts
+            .into_iter()
+            .map(|count| records.by_ref().take(count).flatten().collect())
+            .collect()
+    };
+    let endorsement_hashes: Vec<ActionHash> = records
+        .iter()
+        .flatten()
+        .map(|record| record.action_address().clone())
         .collect();
-    let records: Vec<Record> = HDK
-        .with(|hdk| hdk.borrow().get(get_input))?
+    let revocation_links = get_links_batch(
+        endorsement_hashes,
+        LinkTypes::EndorsementToRevocations,
+    )?;
+    let revocation_counts: Vec<usize> = revocation_links.iter().map(|links| links.len()).collect();
+    let mut revocation_records = get_records_batch(
+            revocation_links
+                .into_iter()
+                .flatten()
+                .map(|link| ActionHash::from(link.target))
+                .collect(),
+        )?
+        .into_iter();
+    let mut revocations = revocation_counts
         .into_iter()
-        .filter_map(|r| r)
-        .collect();
-    Ok(records)
+        .map(|count| first_revocation(revocation_records.by_ref().take(count).flatten()));
+    let mut endorsements_for_reports = Vec::new();
+    for records in records {
+        let mut endorsements = Vec::new();
+        for record in records {
+            let revocation = revocations.next().flatten();
+            if revocation.is_some() && !include_revoked {
+                continue;
+            }
+            endorsements.push(EndorsementWithRevocation {
+                record,
+                revocation,
+            });
+        }
+        endorsements_for_reports.push(endorsements);
+    }
+    Ok(endorsements_for_reports)
+}
+/// The first of the revocations in chain order. Only the endorser can revoke an
+/// endorsement, so they all sit on the same chain, and ties are broken by hash
+fn first_revocation(revocations: impl IntoIterator<Item = Record>) -> Option<Record> {
+    revocations
+        .into_iter()
+        .min_by(|a, b| {
+            a.action()
+                .action_seq()
+                .cmp(&b.action().action_seq())
+                .then_with(|| a.action_address().cmp(b.action_address()))
+        })
+}
+/// The first revocation of the endorsement, if any
+#[hdk_extern]
+pub fn get_endorsement_revocation(endorsement_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let links = get_links(endorsement_hash, LinkTypes::EndorsementToRevocations, None)?;
+    let revocations = get_records_batch(
+        links.into_iter().map(|link| ActionHash::from(link.target)).collect(),
+    )?;
+    Ok(first_revocation(revocations.into_iter().flatten()))
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddEndorsementForReportInput {
+    pub base_report_hash: ActionHash,
+    pub target_endorsement_hash: ActionHash,
+}
+/// Links an endorsement to its report, for when the link made by `create_endorsement` is missing
+#[hdk_extern]
+pub fn add_endorsement_for_report(input: AddEndorsementForReportInput) -> ExternResult<()> {
+    create_link(
+        input.base_report_hash,
+        input.target_endorsement_hash,
+        LinkTypes::ReportToEndorsements,
+        (),
+    )?;
+    Ok(())
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RevokeEndorsementInput {
+    pub endorsement_hash: ActionHash,
+    pub reason: Option<String>,
+}
+#[hdk_extern]
+pub fn revoke_endorsement(input: RevokeEndorsementInput) -> ExternResult<Record> {
+    let revocation_hash = create_entry(
+        &EntryTypes::EndorsementRevocation(EndorsementRevocation {
+            endorsement_hash: input.endorsement_hash.clone(),
+            reason: input.reason,
+        }),
+    )?;
+    create_link(
+        input.endorsement_hash,
+        revocation_hash.clone(),
+        LinkTypes::EndorsementToRevocations,
+        (),
+    )?;
+    let record = get(revocation_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the newly created EndorsementRevocation"))
+            ),
+        )?;
+    Ok(record)
+}
+/// Number of unrevoked endorsements of a report for each stance
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct EndorsementStanceCounts {
+    pub endorse: u32,
+    pub partially_endorse: u32,
+    pub dispute: u32,
+}
+#[hdk_extern]
+pub fn get_endorsement_stance_counts(
+    report_hash: ActionHash,
+) -> ExternResult<EndorsementStanceCounts> {
+    let endorsements = get_endorsements_for_report(GetEndorsementsForReportInput {
+        report_hash,
+        include_revoked: false,
+    })?;
+    let mut counts = EndorsementStanceCounts::default();
+    for endorsement in endorsements {
+        let endorsement: Option<Endorsement> = endorsement
+            .record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        match endorsement.map(|e| e.stance) {
+            Some(EndorsementStance::Endorse) => counts.endorse += 1,
+            Some(EndorsementStance::PartiallyEndorse) => counts.partially_endorse += 1,
+            Some(EndorsementStance::Dispute) => counts.dispute += 1,
+            None => {}
+        }
+    }
+    Ok(counts)
 }
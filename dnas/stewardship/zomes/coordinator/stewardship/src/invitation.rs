@@ -0,0 +1,162 @@
+use hdk::prelude::*;
+use stewardship_integrity::*;
+/// Invites the agents added by an Actant revision. Agents only count as members once
+/// they accept, so nothing is issued if the author is not listed in the revision.
+pub fn invite_added_agents(
+    original_actant_hash: &ActionHash,
+    previous_agents: &[AgentPubKey],
+    revision_hash: &ActionHash,
+    actant: &Actant,
+) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if !actant.agents.contains(&my_pub_key) {
+        return Ok(());
+    }
+    for invitee in actant
+        .agents
+        .iter()
+        .filter(|agent| !previous_agents.contains(agent) && **agent != my_pub_key)
+    {
+        let invitation_hash = create_entry(
+            &EntryTypes::ActantInvitation(ActantInvitation {
+                actant_hash: original_actant_hash.clone(),
+                actant_revision_hash: revision_hash.clone(),
+                invitee: invitee.clone(),
+            }),
+        )?;
+        create_link(
+            invitee.clone(),
+            invitation_hash,
+            LinkTypes::AgentToInvitations,
+            (),
+        )?;
+    }
+    Ok(())
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InviteAgentInput {
+    pub original_actant_hash: ActionHash,
+    pub invitee: AgentPubKey,
+}
+/// Adds the invitee to the latest revision of the Actant, which issues its invitation
+#[hdk_extern]
+pub fn invite_agent(input: InviteAgentInput) -> ExternResult<Record> {
+    let latest = crate::actant::get_actant(input.original_actant_hash.clone())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Actant"))
+            ),
+        )?;
+    let mut actant: Actant = latest
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Latest record must be an Actant"))
+            ),
+        )?;
+    if actant.agents.contains(&input.invitee) {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("The agent is already listed in the Actant"))
+            ),
+        );
+    }
+    actant.agents.push(input.invitee);
+    crate::actant::update_actant(crate::actant::UpdateActantInput {
+        original_actant_hash: input.original_actant_hash,
+        previous_actant_hash: latest.action_address().clone(),
+        updated_actant: actant,
+    })
+}
+/// Invitations addressed to the calling agent that it has neither accepted nor declined
+#[hdk_extern]
+pub fn get_my_invitations(_: ()) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        agent_info()?.agent_initial_pubkey,
+        LinkTypes::AgentToInvitations,
+        None,
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(
+            ActionHash::from(link.target).into(),
+            GetOptions::default(),
+        ))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    let records: Vec<Record> = records.into_iter().filter_map(|r| r).collect();
+    Ok(records)
+}
+fn remove_invitation_links(invitation_hash: &ActionHash) -> ExternResult<()> {
+    let links = get_links(
+        agent_info()?.agent_initial_pubkey,
+        LinkTypes::AgentToInvitations,
+        None,
+    )?;
+    for link in links {
+        if ActionHash::from(link.target) == *invitation_hash {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+#[hdk_extern]
+pub fn accept_invitation(invitation_hash: ActionHash) -> ExternResult<Record> {
+    let invitation: ActantInvitation = get(invitation_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the invitation"))
+            ),
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Record must be an ActantInvitation"))
+            ),
+        )?;
+    let acceptance_hash = create_entry(
+        &EntryTypes::ActantAcceptance(ActantAcceptance {
+            invitation_hash: invitation_hash.clone(),
+            actant_hash: invitation.actant_hash.clone(),
+        }),
+    )?;
+    crate::actant::link_to_actant(&invitation.actant_hash, &invitation.actant_revision_hash)?;
+    remove_invitation_links(&invitation_hash)?;
+    let record = get(acceptance_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the newly created ActantAcceptance"))
+            ),
+        )?;
+    Ok(record)
+}
+/// Records the refusal on the caller's chain, so that the invitation can no longer be
+/// accepted, and drops it from the pending ones
+#[hdk_extern]
+pub fn decline_invitation(invitation_hash: ActionHash) -> ExternResult<()> {
+    let invitation: ActantInvitation = get(invitation_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the invitation"))
+            ),
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Record must be an ActantInvitation"))
+            ),
+        )?;
+    create_entry(
+        &EntryTypes::ActantDecline(ActantDecline {
+            invitation_hash: invitation_hash.clone(),
+            actant_hash: invitation.actant_hash,
+        }),
+    )?;
+    remove_invitation_links(&invitation_hash)
+}
@@ -1,3 +1,13 @@
+pub mod reporting_schedule;
+pub mod compliance;
+pub mod batch;
+pub mod report_status;
+pub mod clause_ratification;
+pub mod clause_transition;
+pub mod invitation;
+pub mod clause_approval;
+pub mod pagination;
+pub mod time_shard;
 pub mod all_actants;
 pub mod all_clauses;
 pub mod endorsement;
@@ -8,6 +18,14 @@ use hdk::prelude::*;
 use stewardship_integrity::*;
 #[hdk_extern]
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
+    let mut functions = BTreeSet::new();
+    functions.insert((zome_info()?.name, "recv_remote_signal".into()));
+    create_cap_grant(CapGrantEntry {
+        tag: "".into(),
+        access: ().into(),
+        functions,
+    })?;
+    schedule("check_my_overdue_obligations")?;
     Ok(InitCallbackResult::Pass)
 }
 #[derive(Serialize, Deserialize, Debug)]
@@ -22,6 +40,21 @@ pub enum Signal {
         original_app_entry: EntryTypes,
     },
     EntryDeleted { action: SignedActionHashed, original_app_entry: EntryTypes },
+    /// The report reached its clause's quorum when the endorsement was committed or, with
+    /// `revocation_hash`, revoked. Sent to the report's author and its holders' agents.
+    ReportVerified {
+        report_hash: ActionHash,
+        endorsement_hash: ActionHash,
+        #[serde(default)]
+        revocation_hash: Option<ActionHash>,
+    },
+    /// Reports the agent's Actants owe, sent periodically by `check_my_overdue_obligations`
+    OverdueObligations { obligations: Vec<reporting_schedule::OverdueObligation> },
+}
+/// Re-emits the signals other agents send to this one, such as `ReportVerified`
+#[hdk_extern]
+pub fn recv_remote_signal(signal: Signal) -> ExternResult<()> {
+    emit_signal(signal)
 }
 #[hdk_extern(infallible)]
 pub fn post_commit(committed_actions: Vec<SignedActionHashed>) {
@@ -79,10 +112,30 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
         }
         Action::Create(_create) => {
             if let Ok(Some(app_entry)) = get_entry_for_action(&action.hashed.hash) {
+                let action_hash = action.hashed.hash.clone();
+                let verified = match &app_entry {
+                    EntryTypes::Endorsement(endorsement) => {
+                        if report_status::reached_quorum(&action_hash, endorsement.report_hash.clone())? {
+                            Some((endorsement.report_hash.clone(), action_hash, None))
+                        } else {
+                            None
+                        }
+                    }
+                    EntryTypes::EndorsementRevocation(revocation) => {
+                        report_status::reached_quorum_by_revocation(&revocation.endorsement_hash)?
+                            .map(|report_hash| {
+                                (report_hash, revocation.endorsement_hash.clone(), Some(action_hash))
+                            })
+                    }
+                    _ => None,
+                };
                 emit_signal(Signal::EntryCreated {
                     action,
                     app_entry,
                 })?;
+                if let Some((report_hash, endorsement_hash, revocation_hash)) = verified {
+                    signal_report_verified(report_hash, endorsement_hash, revocation_hash)?;
+                }
             }
             Ok(())
         }
@@ -112,6 +165,21 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
         _ => Ok(()),
     }
 }
+/// Emits the verification of a report and sends it to the other agents concerned by it
+fn signal_report_verified(
+    report_hash: ActionHash,
+    endorsement_hash: ActionHash,
+    revocation_hash: Option<ActionHash>,
+) -> ExternResult<()> {
+    let audience = report_status::get_report_audience(&report_hash)?;
+    let signal = Signal::ReportVerified {
+        report_hash,
+        endorsement_hash,
+        revocation_hash,
+    };
+    emit_signal(&signal)?;
+    send_remote_signal(&signal, audience)
+}
 fn get_entry_for_action(action_hash: &ActionHash) -> ExternResult<Option<EntryTypes>> {
     let record = match get_details(action_hash.clone(), GetOptions::default())? {
         Some(Details::Record(record_details)) => record_details.record,
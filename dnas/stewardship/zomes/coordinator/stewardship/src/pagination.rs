@@ -0,0 +1,112 @@
+use hdk::prelude::*;
+use stewardship_integrity::*;
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+/// Position of a record in a collection, ordered by creation timestamp and then by hash
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PageCursor {
+    pub timestamp: Timestamp,
+    pub action_hash: ActionHash,
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PageInput {
+    /// Must be at least 1
+    pub page_size: usize,
+    /// Only return records that come after this cursor in the chosen direction
+    pub after: Option<PageCursor>,
+    pub direction: SortDirection,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Page {
+    pub records: Vec<Record>,
+    /// Cursor to pass as `after` to get the next page, `None` on the last page
+    pub next_cursor: Option<PageCursor>,
+}
+/// Most records the unpaginated collection getters return
+pub const MAX_LISTING_SIZE: usize = 500;
+/// Whether `cursor` comes after `after` when walking the collection in `direction`
+pub fn is_past(cursor: &PageCursor, after: Option<&PageCursor>, direction: SortDirection) -> bool {
+    match (after, direction) {
+        (None, _) => true,
+        (Some(after), SortDirection::Ascending) => cursor > after,
+        (Some(after), SortDirection::Descending) => cursor < after,
+    }
+}
+/// Orders the cursors in the direction of `input` and keeps the page that follows its
+/// `after` cursor, along with the cursor of the next page if there is one
+pub fn select_page(
+    mut cursors: Vec<PageCursor>,
+    input: &PageInput,
+) -> (Vec<PageCursor>, Option<PageCursor>) {
+    cursors.sort();
+    if input.direction == SortDirection::Descending {
+        cursors.reverse();
+    }
+    let mut cursors: Vec<PageCursor> = cursors
+        .into_iter()
+        .filter(|cursor| is_past(cursor, input.after.as_ref(), input.direction))
+        .take(input.page_size + 1)
+        .collect();
+    let next_cursor = if cursors.len() > input.page_size {
+        cursors.truncate(input.page_size);
+        cursors.last().cloned()
+    } else {
+        None
+    };
+    (cursors, next_cursor)
+}
+/// Fetches one page of a time sharded collection, ordered by the time its records were
+/// linked. Only the shards the page spans are walked, see `get_time_shard_cursors`
+pub fn get_page(
+    anchor: &str,
+    shards_link_type: LinkTypes,
+    link_type: LinkTypes,
+    input: PageInput,
+) -> ExternResult<Page> {
+    if input.page_size == 0 {
+        return Err(
+            wasm_error!(WasmErrorInner::Guest(String::from("The page size must be at least 1")))
+        );
+    }
+    let cursors = crate::time_shard::get_time_shard_cursors(
+        anchor,
+        shards_link_type,
+        link_type,
+        input.after.as_ref(),
+        input.direction,
+        input.page_size + 1,
+    )?;
+    let (cursors, next_cursor) = select_page(cursors, &input);
+    let get_input: Vec<GetInput> = cursors
+        .into_iter()
+        .map(|cursor| GetInput::new(cursor.action_hash.into(), GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+    Ok(Page { records, next_cursor })
+}
+/// The most recently linked records of a time sharded collection, newest first and at
+/// most `MAX_LISTING_SIZE` of them
+pub fn get_latest(
+    anchor: &str,
+    shards_link_type: LinkTypes,
+    link_type: LinkTypes,
+) -> ExternResult<Vec<Record>> {
+    let page = get_page(
+        anchor,
+        shards_link_type,
+        link_type,
+        PageInput {
+            page_size: MAX_LISTING_SIZE,
+            after: None,
+            direction: SortDirection::Descending,
+        },
+    )?;
+    Ok(page.records)
+}
@@ -1,7 +1,53 @@
 use hdk::prelude::*;
 use stewardship_integrity::*;
+use crate::batch::{earliest_link, get_links_batch, get_records_batch};
 #[hdk_extern]
-pub fn create_report(report: Report) -> ExternResult<Record> {
+pub fn create_report(mut report: Report) -> ExternResult<Record> {
+    if report.actant_revision_hash.is_none() {
+        let actant_record = crate::actant::get_actant(report.actant_hash.clone())?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Could not find the reporting Actant"))
+                ),
+            )?;
+        report.actant_revision_hash = Some(actant_record.action_address().clone());
+    }
+    if report.guardian_revision_hashes.is_empty() {
+        let revision_hash = report.actant_revision_hash.clone().unwrap_or(report.actant_hash.clone());
+        let actant: Actant = get(revision_hash, GetOptions::default())?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Could not find the reporting Actant"))
+                ),
+            )?
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Record must be an Actant"))
+                ),
+            )?;
+        report.guardian_revision_hashes = crate::actant::get_latest_revision_hashes(&actant.guardians)?;
+    }
+    let clause_state = crate::clause_transition::get_clause_state(report.clause_hash.clone())?;
+    if clause_state.status != ClauseStatus::Active {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Reports can only be created against active clauses"))
+            ),
+        );
+    }
+    if let Some(transition_hash) = &report.clause_transition_hash {
+        if Some(transition_hash) != clause_state.transition_hash.as_ref() {
+            return Err(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Reports must cite the transition that made the clause active"))
+                ),
+            );
+        }
+    }
+    report.clause_transition_hash = clause_state.transition_hash;
     let report_hash = create_entry(&EntryTypes::Report(report.clone()))?;
     create_link(
         report.actant_hash.clone(),
@@ -13,7 +59,7 @@ pub fn create_report(report: Report) -> ExternResult<Record> {
         report.clause_hash.clone(),
         report_hash.clone(),
         LinkTypes::ClauseToReports,
-        (),
+        report.report_type.tag(),
     )?;
     let record = get(report_hash.clone(), GetOptions::default())?
         .ok_or(
@@ -23,41 +69,262 @@ pub fn create_report(report: Report) -> ExternResult<Record> {
         )?;
     Ok(record)
 }
+/// A report as it currently stands: the original record, its content with the latest
+/// correction applied and whether it has been retracted
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EffectiveReport {
+    pub record: Record,
+    pub report: Report,
+    pub correction_hash: Option<ActionHash>,
+    pub retracted: bool,
+    pub retraction_hash: Option<ActionHash>,
+}
+/// Applies the latest correction and the retraction, if any, of each report. Corrections
+/// and retractions of all the reports are looked up together. Returns one slot per input,
+/// left empty for missing records and records that are not reports.
+fn get_effective_reports(
+    records: Vec<Option<Record>>,
+) -> ExternResult<Vec<Option<EffectiveReport>>> {
+    let mut reports: Vec<Option<(Record, Report)>> = Vec::new();
+    for record in records {
+        let report = match record {
+            Some(record) => {
+                let report: Option<Report> = record
+                    .entry()
+                    .to_app_option()
+                    .map_err(|e| wasm_error!(e))?;
+                report.map(|report| (record, report))
+            }
+            None => None,
+        };
+        reports.push(report);
+    }
+    let report_hashes: Vec<ActionHash> = reports
+        .iter()
+        .flatten()
+        .map(|(record, _)| record.action_address().clone())
+        .collect();
+    let correction_links = get_links_batch(
+        report_hashes.clone(),
+        LinkTypes::ReportToCorrections,
+    )?;
+    let retraction_links = get_links_batch(report_hashes, LinkTypes::ReportToRetractions)?;
+    let correction_counts: Vec<usize> = correction_links.iter().map(|links| links.len()).collect();
+    let corrections = get_records_batch(
+        correction_links
+            .into_iter()
+            .flatten()
+            .map(|link| ActionHash::from(link.target))
+            .collect(),
+    )?;
+    let mut corrections = corrections.into_iter();
+    let mut found = correction_counts.into_iter().zip(retraction_links);
+    let mut effective_reports = Vec::new();
+    for slot in reports {
+        let (record, mut report) = match slot {
+            Some(slot) => slot,
+            None => {
+                effective_reports.push(None);
+                continue;
+            }
+        };
+        let (correction_count, retraction_links) = found.next().unwrap_or_default();
+        let correction_records: Vec<Record> = corrections
+            .by_ref()
+            .take(correction_count)
+            .flatten()
+            .collect();
+        let mut applied_correction_hash = None;
+        if let Some((correction_hash, correction)) = latest_correction(correction_records)? {
+            report.content = correction.content;
+            applied_correction_hash = Some(correction_hash);
+        }
+        let retraction_hash = earliest_link(&retraction_links)
+            .map(|link| ActionHash::from(link.target.clone()));
+        effective_reports.push(Some(EffectiveReport {
+            record,
+            report,
+            correction_hash: applied_correction_hash,
+            retracted: retraction_hash.is_some(),
+            retraction_hash,
+        }));
+    }
+    Ok(effective_reports)
+}
+/// The last correction of the chain formed through `previous_correction_hash`. When several
+/// corrections follow the same one, the one with the lowest action hash wins: timestamps
+/// are set by their authors and could be backdated.
+fn latest_correction(records: Vec<Record>) -> ExternResult<Option<(ActionHash, ReportCorrection)>> {
+    let mut corrections: Vec<(ActionHash, ReportCorrection)> = Vec::new();
+    for record in records {
+        let correction: Option<ReportCorrection> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        if let Some(correction) = correction {
+            corrections.push((record.action_address().clone(), correction));
+        }
+    }
+    corrections.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut latest: Option<(ActionHash, ReportCorrection)> = None;
+    while let Some(next) = corrections
+        .iter()
+        .find(|(_, correction)| {
+            correction.previous_correction_hash.as_ref() == latest.as_ref().map(|(hash, _)| hash)
+        })
+    {
+        latest = Some(next.clone());
+    }
+    Ok(latest)
+}
+fn get_effective_reports_for_links(links: Vec<Link>) -> ExternResult<Vec<EffectiveReport>> {
+    let records = get_records_batch(
+        links.into_iter().map(|link| ActionHash::from(link.target)).collect(),
+    )?;
+    Ok(get_effective_reports(records)?.into_iter().flatten().collect())
+}
 #[hdk_extern]
-pub fn get_report(report_hash: ActionHash) -> ExternResult<Option<Record>> {
-    get(report_hash, GetOptions::default())
+pub fn get_report(report_hash: ActionHash) -> ExternResult<Option<EffectiveReport>> {
+    match get(report_hash, GetOptions::default())? {
+        Some(record) => Ok(get_effective_reports(vec![Some(record)])?.pop().flatten()),
+        None => Ok(None),
+    }
 }
 #[hdk_extern]
-pub fn get_reports_for_actant(actant_hash: ActionHash) -> ExternResult<Vec<Record>> {
+pub fn get_reports_for_actant(actant_hash: ActionHash) -> ExternResult<Vec<EffectiveReport>> {
     let links = get_links(actant_hash, LinkTypes::ActantToReports, None)?;
-    let get_input: Vec<GetInput> = links
-        .into_iter()
-        .map(|link| GetInput::new(
-            ActionHash::from(link.target).into(),
-            GetOptions::default(),
-        ))
-        .collect();
-    let records: Vec<Record> = HDK
-        .with(|hdk| hdk.borrow().get(get_input))?
-        .into_iter()
-        .filter_map(|r| r)
-        .collect();
-    Ok(records)
+    get_effective_reports_for_links(links)
 }
 #[hdk_extern]
-pub fn get_reports_for_clause(clause_hash: ActionHash) -> ExternResult<Vec<Record>> {
-    let links = get_links(clause_hash, LinkTypes::ClauseToReports, None)?;
-    let get_input: Vec<GetInput> = links
-        .into_iter()
-        .map(|link| GetInput::new(
-            ActionHash::from(link.target).into(),
-            GetOptions::default(),
-        ))
-        .collect();
-    let records: Vec<Record> = HDK
-        .with(|hdk| hdk.borrow().get(get_input))?
-        .into_iter()
-        .filter_map(|r| r)
-        .collect();
-    Ok(records)
+pub fn get_reports_for_clause(clause_hash: ActionHash) -> ExternResult<Vec<EffectiveReport>> {
+    Ok(get_reports_for_clauses(vec![clause_hash])?.pop().unwrap_or_default())
+}
+/// The reports of each clause, in the order of `clause_hashes`, fetched with the same
+/// number of DHT calls however many clauses and reports there are
+pub fn get_reports_for_clauses(
+    clause_hashes: Vec<ActionHash>,
+) -> ExternResult<Vec<Vec<EffectiveReport>>> {
+    let links = get_links_batch(clause_hashes, LinkTypes::ClauseToReports)?;
+    let report_counts: Vec<usize> = links.iter().map(|links| links.len()).collect();
+    let records = get_records_batch(
+        links
+            .into_iter()
+            .flatten()
+            .map(|link| ActionHash::from(link.target))
+            .collect(),
+    )?;
+    // Every link keeps its slot, so reports can be handed back to their clause
+    let mut reports = get_effective_reports(records)?.into_iter();
+    let mut reports_for_clauses = Vec::new();
+    for report_count in report_counts {
+        reports_for_clauses.push(reports.by_ref().take(report_count).flatten().collect());
+    }
+    Ok(reports_for_clauses)
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetReportsForClauseByTypeInput {
+    pub clause_hash: ActionHash,
+    pub report_type: ReportType,
+}
+#[hdk_extern]
+pub fn get_reports_for_clause_by_type(
+    input: GetReportsForClauseByTypeInput,
+) -> ExternResult<Vec<EffectiveReport>> {
+    let tag = input.report_type.tag();
+    let links = get_links(input.clause_hash, LinkTypes::ClauseToReports, Some(tag.clone()))?;
+    // Tags are matched by prefix, so a custom type could also match longer custom types
+    get_effective_reports_for_links(links.into_iter().filter(|link| link.tag == tag).collect())
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddReportForClauseInput {
+    pub base_clause_hash: ActionHash,
+    pub target_report_hash: ActionHash,
+    pub report_type: ReportType,
+}
+/// Links a report to its clause, for when the link made by `create_report` is missing
+#[hdk_extern]
+pub fn add_report_for_clause(input: AddReportForClauseInput) -> ExternResult<()> {
+    create_link(
+        input.base_clause_hash,
+        input.target_report_hash,
+        LinkTypes::ClauseToReports,
+        input.report_type.tag(),
+    )?;
+    Ok(())
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CorrectReportInput {
+    pub report_hash: ActionHash,
+    pub content: String,
+}
+/// Replaces the content of a report, following its current correction. The original stays
+/// reachable through the `record` of the effective report.
+#[hdk_extern]
+pub fn correct_report(input: CorrectReportInput) -> ExternResult<EffectiveReport> {
+    let current = get_report(input.report_hash.clone())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Report"))
+            ),
+        )?;
+    let correction_hash = create_entry(
+        &EntryTypes::ReportCorrection(ReportCorrection {
+            report_hash: input.report_hash.clone(),
+            content: input.content,
+            previous_correction_hash: current.correction_hash,
+        }),
+    )?;
+    create_link(
+        input.report_hash.clone(),
+        correction_hash,
+        LinkTypes::ReportToCorrections,
+        (),
+    )?;
+    get_report(input.report_hash)?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the corrected Report"))
+            ),
+        )
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RetractReportInput {
+    pub report_hash: ActionHash,
+    pub reason: String,
+}
+#[hdk_extern]
+pub fn retract_report(input: RetractReportInput) -> ExternResult<EffectiveReport> {
+    let retraction_hash = create_entry(
+        &EntryTypes::ReportRetraction(ReportRetraction {
+            report_hash: input.report_hash.clone(),
+            reason: input.reason,
+        }),
+    )?;
+    create_link(
+        input.report_hash.clone(),
+        retraction_hash,
+        LinkTypes::ReportToRetractions,
+        (),
+    )?;
+    get_report(input.report_hash)?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the retracted Report"))
+            ),
+        )
+}
+/// The built-in report types followed by the custom ones of this network
+#[hdk_extern]
+pub fn get_report_types(_: ()) -> ExternResult<Vec<ReportType>> {
+    let mut report_types = vec![
+        ReportType::Progress,
+        ReportType::Breach,
+        ReportType::Incident,
+        ReportType::Fulfilment,
+        ReportType::Other,
+    ];
+    report_types.extend(
+        DnaProperties::get()?.custom_report_types.into_iter().map(ReportType::Custom),
+    );
+    Ok(report_types)
 }
@@ -0,0 +1,220 @@
+use hdk::prelude::*;
+use stewardship_integrity::*;
+use crate::endorsement::EndorsementWithRevocation;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum VerificationStatus {
+    /// Fewer right holders than the clause's quorum have endorsed the report
+    Pending,
+    /// At least the quorum of right holders endorsed the report and none disputes it
+    Verified,
+    /// At least one right holder disputes the report
+    Disputed,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReportStatus {
+    pub status: VerificationStatus,
+    pub quorum: u32,
+    /// Right holders with an unrevoked endorsement of the report, sorted by hash
+    pub endorsing_actants: Vec<ActionHash>,
+    /// Right holders with an unrevoked dispute of the report, sorted by hash
+    pub disputing_actants: Vec<ActionHash>,
+}
+/// Computes the status of a report from its unrevoked endorsements. Each right holder
+/// counts once however many of its agents endorsed; partial endorsements count neither way.
+#[hdk_extern]
+pub fn get_report_status(report_hash: ActionHash) -> ExternResult<ReportStatus> {
+    let (clause, endorsements) = get_clause_and_endorsements(report_hash)?;
+    status_from_endorsements(&clause, &endorsements)
+}
+/// The clause the report was filed against, and the report's unrevoked endorsements
+fn get_clause_and_endorsements(
+    report_hash: ActionHash,
+) -> ExternResult<(Clause, Vec<EndorsementWithRevocation>)> {
+    let report: Report = get(report_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Could not find the Report")))
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Record must be a Report")))
+        )?;
+    let clause: Clause = get(report.clause_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Could not find the Clause")))
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Record must be a Clause")))
+        )?;
+    let endorsements = crate::endorsement::get_endorsements_for_report(
+        crate::endorsement::GetEndorsementsForReportInput {
+            report_hash,
+            include_revoked: false,
+        },
+    )?;
+    Ok((clause, endorsements))
+}
+/// Computes the status of a report on `clause` from its unrevoked endorsements
+pub fn status_from_endorsements(
+    clause: &Clause,
+    endorsements: &[EndorsementWithRevocation],
+) -> ExternResult<ReportStatus> {
+    let mut endorsing_actants: Vec<ActionHash> = Vec::new();
+    let mut disputing_actants: Vec<ActionHash> = Vec::new();
+    for endorsement in endorsements {
+        if endorsement.revocation.is_some() {
+            continue;
+        }
+        let endorsement: Endorsement = endorsement
+            .record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(
+                wasm_error!(WasmErrorInner::Guest(String::from("Record must be an Endorsement")))
+            )?;
+        // Validation rejects endorsements that do not name their right holder
+        let actant_hash = endorsement
+            .actant_hash
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Endorsement must name the right holder it speaks for"))
+                ),
+            )?;
+        let stance = endorsement.stance;
+        if !clause.right_holders.contains(&actant_hash) {
+            continue;
+        }
+        let actants = match stance {
+            EndorsementStance::Endorse => &mut endorsing_actants,
+            EndorsementStance::Dispute => &mut disputing_actants,
+            EndorsementStance::PartiallyEndorse => continue,
+        };
+        if !actants.contains(&actant_hash) {
+            actants.push(actant_hash);
+        }
+    }
+    endorsing_actants.sort();
+    disputing_actants.sort();
+    let status = if !disputing_actants.is_empty() {
+        VerificationStatus::Disputed
+    } else if endorsing_actants.len() >= clause.endorsement_quorum as usize {
+        VerificationStatus::Verified
+    } else {
+        VerificationStatus::Pending
+    };
+    Ok(ReportStatus {
+        status,
+        quorum: clause.endorsement_quorum,
+        endorsing_actants,
+        disputing_actants,
+    })
+}
+/// Whether the endorsement at `endorsement_hash` is the one that made the report verified.
+/// The status before it is derived from the same endorsements with it left out.
+pub fn reached_quorum(
+    endorsement_hash: &ActionHash,
+    report_hash: ActionHash,
+) -> ExternResult<bool> {
+    let (clause, mut endorsements) = get_clause_and_endorsements(report_hash)?;
+    // The endorsement was just committed, so it may not be linked from the report yet
+    if !endorsements
+        .iter()
+        .any(|endorsement| endorsement.record.action_address() == endorsement_hash)
+    {
+        let record = get(endorsement_hash.clone(), GetOptions::default())?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Could not find the Endorsement"))
+                ),
+            )?;
+        endorsements.push(EndorsementWithRevocation {
+            record,
+            revocation: None,
+        });
+    }
+    if status_from_endorsements(&clause, &endorsements)?.status != VerificationStatus::Verified {
+        return Ok(false);
+    }
+    endorsements.retain(|endorsement| endorsement.record.action_address() != endorsement_hash);
+    Ok(status_from_endorsements(&clause, &endorsements)?.status != VerificationStatus::Verified)
+}
+/// The report whose endorsement at `endorsement_hash` was just revoked, if revoking it is
+/// what made the report verified, as revoking the only dispute can
+pub fn reached_quorum_by_revocation(endorsement_hash: &ActionHash) -> ExternResult<Option<ActionHash>> {
+    let record = get(endorsement_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Endorsement"))
+            ),
+        )?;
+    let endorsement: Endorsement = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Record must be an Endorsement")))
+        )?;
+    let (clause, mut endorsements) = get_clause_and_endorsements(endorsement.report_hash.clone())?;
+    // The revocation was just committed, so the endorsement may still be listed as unrevoked
+    endorsements.retain(|endorsement| endorsement.record.action_address() != endorsement_hash);
+    if status_from_endorsements(&clause, &endorsements)?.status != VerificationStatus::Verified {
+        return Ok(None);
+    }
+    endorsements.push(EndorsementWithRevocation {
+        record,
+        revocation: None,
+    });
+    if status_from_endorsements(&clause, &endorsements)?.status == VerificationStatus::Verified {
+        return Ok(None);
+    }
+    Ok(Some(endorsement.report_hash))
+}
+/// The author of the report and the agents of the holders of its clause, other than the
+/// calling agent
+pub fn get_report_audience(report_hash: &ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+    let record = get(report_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Report"))
+            ),
+        )?;
+    let report: Report = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Record must be a Report")))
+        )?;
+    let clause: Clause = get(report.clause_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Could not find the Clause"))
+            ),
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Record must be a Clause")))
+        )?;
+    let mut agents = vec![record.action().author().clone()];
+    for holder_hash in clause.right_holders.iter().chain(clause.responsibilty_holders.iter()) {
+        if let Some(holder) = crate::actant::get_actant(holder_hash.clone())? {
+            let actant: Option<Actant> = holder
+                .entry()
+                .to_app_option()
+                .map_err(|e| wasm_error!(e))?;
+            agents.extend(actant.map(|actant| actant.agents).unwrap_or_default());
+        }
+    }
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    agents.retain(|agent| *agent != my_pub_key);
+    agents.sort();
+    agents.dedup();
+    Ok(agents)
+}
@@ -0,0 +1,160 @@
+use hdk::prelude::*;
+use stewardship_integrity::*;
+use crate::clause::{get_clauses_for_actant, GetClausesForActantInput};
+use crate::pagination::{get_page, PageCursor, PageInput};
+use crate::report::get_reports_for_clauses;
+/// A responsibility holder that has not reported on a clause for the last reporting period
+/// whose deadline has passed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OverdueObligation {
+    pub clause_hash: ActionHash,
+    pub actant_hash: ActionHash,
+    pub period_start: Timestamp,
+    pub due_at: Timestamp,
+    /// Latest unretracted report of the holder on the clause, from any period
+    pub last_report_at: Option<Timestamp>,
+}
+/// The start and deadline of the latest reporting period that is due by `now`
+fn last_due_period(schedule: &ReportingSchedule, now: Timestamp) -> Option<(Timestamp, Timestamp)> {
+    let interval = i64::try_from(schedule.interval_secs).ok()?.checked_mul(1_000_000)?;
+    let grace_period = i64::try_from(schedule.grace_period_secs).ok()?.checked_mul(1_000_000)?;
+    let elapsed = now
+        .as_micros()
+        .checked_sub(schedule.start.as_micros())?
+        .checked_sub(grace_period)?;
+    if elapsed < interval {
+        return None;
+    }
+    let period_start = schedule
+        .start
+        .as_micros()
+        .checked_add((elapsed / interval - 1).checked_mul(interval)?)?;
+    let due_at = period_start.checked_add(interval)?.checked_add(grace_period)?;
+    Some((Timestamp::from_micros(period_start), Timestamp::from_micros(due_at)))
+}
+/// Overdue obligations on active scheduled clauses: of `actant_hash` when given, or of
+/// every responsibility holder of the clauses listed by `get_all_clauses` otherwise. Use
+/// `get_overdue_obligations_page` to check older clauses
+#[hdk_extern]
+pub fn get_overdue_obligations(
+    actant_hash: Option<ActionHash>,
+) -> ExternResult<Vec<OverdueObligation>> {
+    let records = match &actant_hash {
+        Some(actant_hash) => {
+            get_clauses_for_actant(GetClausesForActantInput {
+                    actant_hash: actant_hash.clone(),
+                    role: Some(ClauseRole::ResponsibilityHolder),
+                })?
+                .into_iter()
+                .map(|clause| clause.record)
+                .collect()
+        }
+        None => crate::all_clauses::get_all_clauses(None)?,
+    };
+    overdue_obligations(records, actant_hash.as_ref())
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OverdueObligationsPage {
+    pub obligations: Vec<OverdueObligation>,
+    /// Cursor to pass as `after` to check the next page of clauses, `None` on the last page
+    pub next_cursor: Option<PageCursor>,
+}
+/// Overdue obligations of every responsibility holder on one page of all clauses
+#[hdk_extern]
+pub fn get_overdue_obligations_page(input: PageInput) -> ExternResult<OverdueObligationsPage> {
+    let page = get_page("all_clauses", LinkTypes::AllClausesShards, LinkTypes::AllClauses, input)?;
+    Ok(OverdueObligationsPage {
+        obligations: overdue_obligations(page.records, None)?,
+        next_cursor: page.next_cursor,
+    })
+}
+/// Overdue obligations on the clause records that are active and scheduled, of
+/// `actant_hash` only when given
+fn overdue_obligations(
+    records: Vec<Record>,
+    actant_hash: Option<&ActionHash>,
+) -> ExternResult<Vec<OverdueObligation>> {
+    let mut scheduled_clauses: Vec<(ActionHash, Clause, ReportingSchedule)> = Vec::new();
+    for record in records {
+        let clause: Option<Clause> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        let clause = match clause {
+            Some(clause) => clause,
+            None => continue,
+        };
+        let schedule = match clause.reporting_schedule.clone() {
+            Some(schedule) => schedule,
+            None => continue,
+        };
+        scheduled_clauses.push((record.action_address().clone(), clause, schedule));
+    }
+    let states = crate::clause_transition::get_clause_states(
+        scheduled_clauses.iter().map(|(clause_hash, _, _)| clause_hash.clone()).collect(),
+    )?;
+    let scheduled_clauses: Vec<(ActionHash, Clause, ReportingSchedule)> = scheduled_clauses
+        .into_iter()
+        .zip(states)
+        .filter(|(_, state)| state.status == ClauseStatus::Active)
+        .map(|(scheduled_clause, _)| scheduled_clause)
+        .collect();
+    let reports_for_clauses = get_reports_for_clauses(
+        scheduled_clauses.iter().map(|(clause_hash, _, _)| clause_hash.clone()).collect(),
+    )?;
+    let now = sys_time()?;
+    let mut obligations = Vec::new();
+    for ((clause_hash, clause, schedule), reports) in scheduled_clauses
+        .into_iter()
+        .zip(reports_for_clauses)
+    {
+        let (period_start, due_at) = match last_due_period(&schedule, now) {
+            Some(period) => period,
+            None => continue,
+        };
+        for holder_hash in clause.responsibilty_holders {
+            if actant_hash.map_or(false, |actant_hash| *actant_hash != holder_hash) {
+                continue;
+            }
+            let last_report_at = reports
+                .iter()
+                .filter(|report| !report.retracted && report.report.actant_hash == holder_hash)
+                .map(|report| report.record.action().timestamp())
+                .max();
+            if last_report_at.map_or(true, |last_report_at| last_report_at < period_start) {
+                obligations.push(OverdueObligation {
+                    clause_hash: clause_hash.clone(),
+                    actant_hash: holder_hash,
+                    period_start,
+                    due_at,
+                    last_report_at,
+                });
+            }
+        }
+    }
+    Ok(obligations)
+}
+/// Runs every hour and signals the UI of the obligations of the agent's own Actants
+/// that are overdue
+#[hdk_extern(infallible)]
+pub fn check_my_overdue_obligations(_: Option<Schedule>) -> Option<Schedule> {
+    if let Err(err) = signal_my_overdue_obligations() {
+        error!("Error checking overdue obligations: {:?}", err);
+    }
+    Some(Schedule::Persisted("0 0 * * * * *".to_string()))
+}
+fn signal_my_overdue_obligations() -> ExternResult<()> {
+    let mut obligations = Vec::new();
+    for actant in crate::actant::get_my_actants(())? {
+        let original_actant_hash = must_get_original_action_hash(
+            actant.action_address().clone(),
+        )?;
+        obligations.extend(get_overdue_obligations(Some(original_actant_hash))?);
+    }
+    if !obligations.is_empty() {
+        emit_signal(crate::Signal::OverdueObligations {
+            obligations,
+        })?;
+    }
+    Ok(())
+}
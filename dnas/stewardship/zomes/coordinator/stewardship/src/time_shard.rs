@@ -0,0 +1,230 @@
+use hdk::prelude::*;
+use stewardship_integrity::*;
+use crate::pagination::{is_past, PageCursor, SortDirection};
+/// Inclusive range of creation times
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TimeRange {
+    pub from: Timestamp,
+    pub to: Timestamp,
+}
+/// Links a newly created record from the day shard of its collection anchor
+pub fn link_to_time_shard(
+    anchor: &str,
+    shards_link_type: LinkTypes,
+    link_type: LinkTypes,
+    record: &Record,
+) -> ExternResult<()> {
+    let shard = time_shard_path(anchor, record.action().timestamp()).typed(shards_link_type)?;
+    shard.ensure()?;
+    create_link(
+        shard.path_entry_hash()?,
+        record.action_address().clone(),
+        link_type,
+        (),
+    )?;
+    Ok(())
+}
+/// Walks the year, month and day shards of a collection anchor, only visiting the
+/// shards that overlap `range`, and returns the links found on the day shards
+pub fn get_time_shard_links(
+    anchor: &str,
+    shards_link_type: LinkTypes,
+    link_type: LinkTypes,
+    range: TimeRange,
+) -> ExternResult<Vec<Link>> {
+    let bounds = (shard_date(range.from), shard_date(range.to));
+    let root = Path::from(anchor).typed(shards_link_type)?;
+    let mut links = Vec::new();
+    collect_shard_links(root, Vec::new(), &bounds, link_type, &mut links)?;
+    Ok(links)
+}
+fn collect_shard_links(
+    path: TypedPath,
+    date_prefix: Vec<u32>,
+    bounds: &([u32; 3], [u32; 3]),
+    link_type: LinkTypes,
+    links: &mut Vec<Link>,
+) -> ExternResult<()> {
+    if date_prefix.len() == 3 {
+        links.extend(get_links(path.path_entry_hash()?, link_type, None)?);
+        return Ok(());
+    }
+    for child in path.children_paths()? {
+        // Skip children that are not numeric date components rather than failing the walk
+        let value: u32 = match child
+            .leaf()
+            .and_then(|component| String::try_from(component).ok())
+            .and_then(|component| component.parse().ok())
+        {
+            Some(value) => value,
+            None => continue,
+        };
+        let mut child_prefix = date_prefix.clone();
+        child_prefix.push(value);
+        let (from, to) = bounds;
+        let depth = child_prefix.len();
+        if child_prefix[..] < from[..depth] || child_prefix[..] > to[..depth] {
+            continue;
+        }
+        collect_shard_links(child, child_prefix, bounds, link_type, links)?;
+    }
+    Ok(())
+}
+/// Path of the shard of a collection anchor for a date prefix: the anchor itself, a
+/// year, a month or a day
+fn shard_prefix_path(anchor: &str, date_prefix: &[u32]) -> Path {
+    let mut path = anchor.to_string();
+    for (depth, value) in date_prefix.iter().enumerate() {
+        match depth {
+            0 => path.push_str(&format!(".{}", value)),
+            _ => path.push_str(&format!(".{:02}", value)),
+        }
+    }
+    Path::from(path)
+}
+/// Walks the day shards of a collection anchor in `direction`, starting from the shard of
+/// `after`, and stops as soon as `limit` of the links past `after` are known to come
+/// first. A page therefore costs DHT calls for the days it spans rather than for the
+/// whole history of the collection
+pub fn get_time_shard_cursors(
+    anchor: &str,
+    shards_link_type: LinkTypes,
+    link_type: LinkTypes,
+    after: Option<&PageCursor>,
+    direction: SortDirection,
+    limit: usize,
+) -> ExternResult<Vec<PageCursor>> {
+    walk_shards_in_order(
+        |date_prefix| {
+            let children = shard_prefix_path(anchor, date_prefix)
+                .typed(shards_link_type)?
+                .children_paths()?;
+            // Skip children that are not numeric date components rather than failing the walk
+            Ok(
+                children
+                    .into_iter()
+                    .filter_map(|child| {
+                        child
+                            .leaf()
+                            .and_then(|component| String::try_from(component).ok())
+                            .and_then(|component| component.parse().ok())
+                    })
+                    .collect(),
+            )
+        },
+        |date| {
+            let links = get_links(
+                shard_prefix_path(anchor, date).path_entry_hash()?,
+                link_type,
+                None,
+            )?;
+            Ok(
+                links
+                    .into_iter()
+                    .map(|link| PageCursor {
+                        timestamp: link.timestamp,
+                        action_hash: ActionHash::from(link.target),
+                    })
+                    .collect(),
+            )
+        },
+        after,
+        direction,
+        limit,
+    )
+}
+/// Visits day shards in `direction` through `children`, which lists the date components
+/// under a date prefix, and `day_cursors`, which lists the entries of a day shard. Entries
+/// are linked from the shard of their record's creation, which is their own day or the
+/// day before, so once a day shard has been visited every entry of the days up to it, or
+/// from the day after it when descending, is known. The walk stops once `limit` of those
+/// come after `after`.
+pub fn walk_shards_in_order(
+    children: impl FnMut(&[u32]) -> ExternResult<Vec<u32>>,
+    day_cursors: impl FnMut(&[u32]) -> ExternResult<Vec<PageCursor>>,
+    after: Option<&PageCursor>,
+    direction: SortDirection,
+    limit: usize,
+) -> ExternResult<Vec<PageCursor>> {
+    // Entries past `after` sit in its day shard or later ones, or in the day before it
+    // when ascending
+    let start = after.map(|after| match direction {
+        SortDirection::Ascending => shard_date(
+            Timestamp::from_micros(after.timestamp.as_micros().saturating_sub(86_400_000_000)),
+        ),
+        SortDirection::Descending => shard_date(after.timestamp),
+    });
+    let mut walk = ShardWalk {
+        children,
+        day_cursors,
+        after,
+        direction,
+        limit,
+        start,
+        cursors: Vec::new(),
+    };
+    walk.visit(Vec::new())?;
+    Ok(walk.cursors)
+}
+struct ShardWalk<'a, C, D> {
+    children: C,
+    day_cursors: D,
+    after: Option<&'a PageCursor>,
+    direction: SortDirection,
+    limit: usize,
+    start: Option<[u32; 3]>,
+    cursors: Vec<PageCursor>,
+}
+impl<'a, C, D> ShardWalk<'a, C, D>
+where
+    C: FnMut(&[u32]) -> ExternResult<Vec<u32>>,
+    D: FnMut(&[u32]) -> ExternResult<Vec<PageCursor>>,
+{
+    /// Visits the shards under `date_prefix` in order, returning whether the walk is done
+    fn visit(&mut self, date_prefix: Vec<u32>) -> ExternResult<bool> {
+        if date_prefix.len() == 3 {
+            let (after, direction) = (self.after, self.direction);
+            self.cursors.extend(
+                (self.day_cursors)(&date_prefix[..])?
+                    .into_iter()
+                    .filter(|cursor| is_past(cursor, after, direction)),
+            );
+            let settled = self
+                .cursors
+                .iter()
+                .filter(|cursor| {
+                    let date = shard_date(cursor.timestamp);
+                    match direction {
+                        SortDirection::Ascending => date[..] <= date_prefix[..],
+                        SortDirection::Descending => date[..] > date_prefix[..],
+                    }
+                })
+                .count();
+            return Ok(settled >= self.limit);
+        }
+        let mut values = (self.children)(&date_prefix[..])?;
+        values.sort();
+        values.dedup();
+        if self.direction == SortDirection::Descending {
+            values.reverse();
+        }
+        for value in values {
+            let mut child_prefix = date_prefix.clone();
+            child_prefix.push(value);
+            if let Some(start) = &self.start {
+                let depth = child_prefix.len();
+                let before_start = match self.direction {
+                    SortDirection::Ascending => child_prefix[..] < start[..depth],
+                    SortDirection::Descending => child_prefix[..] > start[..depth],
+                };
+                if before_start {
+                    continue;
+                }
+            }
+            if self.visit(child_prefix)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
@@ -4,11 +4,13 @@
 
 use hdk::prelude::*;
 use holochain::test_utils::consistency_10s;
+use holochain::conductor::api::error::ConductorApiResult;
 use holochain::{conductor::config::ConductorConfig, sweettest::*};
 
 use stewardship_integrity::*;
 
-use stewardship::actant::UpdateActantInput;
+use stewardship::actant::{ActantHeads, ActantRevision, ResolveActantConflictInput, SetActantRoleInput, UpdateActantInput};
+use stewardship::invitation::InviteAgentInput;
 
 mod common;
 use common::{create_actant, sample_actant_1, sample_actant_2};
@@ -181,3 +183,722 @@ async fn create_and_delete_actant() {
         
     assert!(get_record.is_none());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn non_member_cannot_update_actant() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let sample_1 = sample_actant_1(&conductors[0], &alice_zome).await;
+    
+    // Alice creates a Actant
+    let record: Record = create_actant(&conductors[0], &alice_zome, sample_1.clone()).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+        
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let sample_2 = sample_actant_2(&conductors[1], &bob_zome).await;
+    let input = UpdateActantInput {
+      original_actant_hash: original_action_hash.clone(),
+      previous_actant_hash: original_action_hash.clone(),
+      updated_actant: sample_2.clone(),
+    };
+    
+    // Bob is not an agent of the Actant, so his update is rejected
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "update_actant", input)
+        .await;
+    assert!(result.is_err());
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let get_record: Option<Record> = conductors[1]
+        .call(&bob_zome, "get_actant", original_action_hash.clone())
+        .await;
+  
+    assert_eq!(record, get_record.unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn non_member_cannot_delete_actant() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let sample_1 = sample_actant_1(&conductors[0], &alice_zome).await;
+    
+    // Alice creates a Actant
+    let record: Record = create_actant(&conductors[0], &alice_zome, sample_1.clone()).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob is not an agent of the Actant, so his delete is rejected
+    let result: ConductorApiResult<ActionHash> = conductors[1]
+        .call_fallible(&bob_zome, "delete_actant", original_action_hash.clone())
+        .await;
+    assert!(result.is_err());
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let get_record: Option<Record> = conductors[1]
+        .call(&bob_zome, "get_actant", original_action_hash.clone())
+        .await;
+        
+    assert_eq!(record, get_record.unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn create_update_and_get_actant_history() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    let bob_pub_key = bob_zome.cell_id().agent_pubkey().clone();
+    
+    let sample_1 = sample_actant_1(&conductors[0], &alice_zome).await;
+    
+    // Alice creates a Actant
+    let record: Record = create_actant(&conductors[0], &alice_zome, sample_1.clone()).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+    
+    // Alice renames the Actant
+    let mut sample_2 = sample_1.clone();
+    sample_2.name = "Lorem ipsum 2".to_string();
+    let update_record_1: Record = conductors[0]
+        .call(&alice_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: original_action_hash.clone(),
+          updated_actant: sample_2.clone(),
+        })
+        .await;
+    
+    // Alice adds Bob to the Actant
+    let mut sample_3 = sample_2.clone();
+    sample_3.agents.push(bob_pub_key.clone());
+    let update_record_2: Record = conductors[0]
+        .call(&alice_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: update_record_1.signed_action.hashed.hash.clone(),
+          updated_actant: sample_3.clone(),
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let history: Vec<ActantRevision> = conductors[1]
+        .call(&bob_zome, "get_actant_history", original_action_hash.clone())
+        .await;
+    
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[0].record, record);
+    assert!(history[0].diff.is_none());
+    
+    assert_eq!(history[1].record, update_record_1);
+    let diff = history[1].diff.clone().unwrap();
+    assert_eq!(diff.name.unwrap().current, sample_2.name);
+    assert!(diff.added_agents.is_empty());
+    
+    assert_eq!(history[2].record, update_record_2);
+    let diff = history[2].diff.clone().unwrap();
+    assert!(diff.name.is_none());
+    assert_eq!(diff.added_agents, vec![bob_pub_key]);
+    assert!(diff.removed_agents.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn concurrent_actant_updates_are_resolved_deterministically() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Alice creates a Actant with both Alice and Bob as agents
+    let mut sample_1 = sample_actant_1(&conductors[0], &alice_zome).await;
+    sample_1.agents.push(bob_zome.cell_id().agent_pubkey().clone());
+    let record: Record = create_actant(&conductors[0], &alice_zome, sample_1.clone()).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob accepts the invitation issued when Alice listed him
+    let invitations: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_invitations", ())
+        .await;
+    let _acceptance: Record = conductors[1]
+        .call(&bob_zome, "accept_invitation", invitations[0].signed_action.hashed.hash.clone())
+        .await;
+    
+    // Alice and Bob both update the original revision
+    let mut alice_sample = sample_1.clone();
+    alice_sample.name = "Alice's name".to_string();
+    let _alice_update: Record = conductors[0]
+        .call(&alice_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: original_action_hash.clone(),
+          updated_actant: alice_sample.clone(),
+        })
+        .await;
+    let mut bob_sample = sample_1.clone();
+    bob_sample.name = "Bob's name".to_string();
+    let _bob_update: Record = conductors[1]
+        .call(&bob_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: original_action_hash.clone(),
+          updated_actant: bob_sample.clone(),
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let alice_heads: Option<ActantHeads> = conductors[0]
+        .call(&alice_zome, "get_actant_heads", original_action_hash.clone())
+        .await;
+    let bob_heads: Option<ActantHeads> = conductors[1]
+        .call(&bob_zome, "get_actant_heads", original_action_hash.clone())
+        .await;
+    let alice_heads = alice_heads.unwrap();
+    assert_eq!(alice_heads.conflicting.len(), 1);
+    assert_eq!(alice_heads, bob_heads.unwrap());
+    
+    // Alice merges both branches
+    let mut merged_sample = sample_1.clone();
+    merged_sample.name = "Merged name".to_string();
+    let merge_record: Record = conductors[0]
+        .call(&alice_zome, "resolve_actant_conflict", ResolveActantConflictInput {
+          original_actant_hash: original_action_hash.clone(),
+          merged_actant: merged_sample.clone(),
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let bob_heads: Option<ActantHeads> = conductors[1]
+        .call(&bob_zome, "get_actant_heads", original_action_hash.clone())
+        .await;
+    let bob_heads = bob_heads.unwrap();
+    assert!(bob_heads.conflicting.is_empty());
+    assert_eq!(bob_heads.latest, merge_record);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn non_member_cannot_merge_actant_revisions() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Alice creates an Actant and updates its original revision twice, forking it
+    let sample_1 = sample_actant_1(&conductors[0], &alice_zome).await;
+    let record: Record = create_actant(&conductors[0], &alice_zome, sample_1.clone()).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+    for name in ["First name", "Second name"] {
+        let mut sample = sample_1.clone();
+        sample.name = name.to_string();
+        let _update: Record = conductors[0]
+            .call(&alice_zome, "update_actant", UpdateActantInput {
+              original_actant_hash: original_action_hash.clone(),
+              previous_actant_hash: original_action_hash.clone(),
+              updated_actant: sample,
+            })
+            .await;
+    }
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob is not a member, so his merge is rejected and the fork remains
+    let mut merged_sample = sample_1.clone();
+    merged_sample.name = "Merged name".to_string();
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "resolve_actant_conflict", ResolveActantConflictInput {
+          original_actant_hash: original_action_hash.clone(),
+          merged_actant: merged_sample,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let alice_heads: Option<ActantHeads> = conductors[0]
+        .call(&alice_zome, "get_actant_heads", original_action_hash.clone())
+        .await;
+    assert_eq!(alice_heads.unwrap().conflicting.len(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn actant_membership_is_indexed_by_agent() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    let bob_pub_key = bob_zome.cell_id().agent_pubkey().clone();
+    
+    let sample_1 = sample_actant_1(&conductors[0], &alice_zome).await;
+    
+    // Alice creates a Actant
+    let record: Record = create_actant(&conductors[0], &alice_zome, sample_1.clone()).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+    
+    let my_actants: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_my_actants", ())
+        .await;
+    assert_eq!(my_actants, vec![record.clone()]);
+    
+    // Alice adds Bob to the Actant
+    let mut sample_2 = sample_1.clone();
+    sample_2.agents.push(bob_pub_key.clone());
+    let update_record: Record = conductors[0]
+        .call(&alice_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: original_action_hash.clone(),
+          updated_actant: sample_2.clone(),
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob is only indexed once he accepts the invitation
+    let invitations: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_invitations", ())
+        .await;
+    let _acceptance: Record = conductors[1]
+        .call(&bob_zome, "accept_invitation", invitations[0].signed_action.hashed.hash.clone())
+        .await;
+    
+    let my_actants: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_actants", ())
+        .await;
+    assert_eq!(my_actants, vec![update_record.clone()]);
+    
+    // Alice removes Bob from the Actant
+    let _update_record: Record = conductors[0]
+        .call(&alice_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: update_record.signed_action.hashed.hash.clone(),
+          updated_actant: sample_1.clone(),
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let actants: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_actants_for_agent", bob_pub_key.clone())
+        .await;
+    assert!(actants.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn later_member_can_remove_earlier_member() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    let alice_pub_key = alice_zome.cell_id().agent_pubkey().clone();
+    let bob_pub_key = bob_zome.cell_id().agent_pubkey().clone();
+    
+    // Alice creates an Actant and adds Bob as an admin
+    let sample_1 = sample_actant_1(&conductors[0], &alice_zome).await;
+    let record: Record = create_actant(&conductors[0], &alice_zome, sample_1.clone()).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+    let mut sample_2 = sample_1.clone();
+    sample_2.agents.push(bob_pub_key.clone());
+    sample_2.admins.push(bob_pub_key.clone());
+    let update_record: Record = conductors[0]
+        .call(&alice_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: original_action_hash.clone(),
+          updated_actant: sample_2.clone(),
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let invitations: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_invitations", ())
+        .await;
+    let _acceptance: Record = conductors[1]
+        .call(&bob_zome, "accept_invitation", invitations[0].signed_action.hashed.hash.clone())
+        .await;
+    
+    // Bob, who was not listed in the revision that added Alice, removes her
+    let mut sample_3 = sample_2.clone();
+    sample_3.agents.retain(|agent| *agent != alice_pub_key);
+    sample_3.admins.retain(|agent| *agent != alice_pub_key);
+    let _update_record: Record = conductors[1]
+        .call(&bob_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: update_record.signed_action.hashed.hash.clone(),
+          updated_actant: sample_3,
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let actants: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_actants_for_agent", alice_pub_key.clone())
+        .await;
+    assert!(actants.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn invited_agent_is_member_only_after_accepting() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Alice creates a Actant and invites Bob
+    let record: Record = create_actant(&conductors[0], &alice_zome, sample_actant_1(&conductors[0], &alice_zome).await).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+    let invite_record: Record = conductors[0]
+        .call(&alice_zome, "invite_agent", InviteAgentInput {
+          original_actant_hash: original_action_hash.clone(),
+          invitee: bob_zome.cell_id().agent_pubkey().clone(),
+        })
+        .await;
+    let mut sample_2: Actant = invite_record.entry().to_app_option().unwrap().unwrap();
+    sample_2.name = "Renamed by Bob".to_string();
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob is listed but has not accepted yet
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: invite_record.signed_action.hashed.hash.clone(),
+          updated_actant: sample_2.clone(),
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Nor is the Actant listed among his own yet
+    let my_actants: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_actants", ())
+        .await;
+    assert!(my_actants.is_empty());
+    
+    let invitations: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_invitations", ())
+        .await;
+    assert_eq!(invitations.len(), 1);
+    let _acceptance: Record = conductors[1]
+        .call(&bob_zome, "accept_invitation", invitations[0].signed_action.hashed.hash.clone())
+        .await;
+    
+    let my_actants: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_actants", ())
+        .await;
+    assert_eq!(my_actants.len(), 1);
+    
+    let invitations: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_invitations", ())
+        .await;
+    assert!(invitations.is_empty());
+    
+    // Once accepted, Bob can update the Actant and leave it
+    let _update_record: Record = conductors[1]
+        .call(&bob_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: invite_record.signed_action.hashed.hash.clone(),
+          updated_actant: sample_2.clone(),
+        })
+        .await;
+    let leave_record: Record = conductors[1]
+        .call(&bob_zome, "leave_actant", original_action_hash.clone())
+        .await;
+    let actant: Actant = leave_record.entry().to_app_option().unwrap().unwrap();
+    assert!(!actant.agents.contains(bob_zome.cell_id().agent_pubkey()));
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Once added again, Bob's earlier acceptance no longer counts
+    let reinvite_record: Record = conductors[0]
+        .call(&alice_zome, "invite_agent", InviteAgentInput {
+          original_actant_hash: original_action_hash.clone(),
+          invitee: bob_zome.cell_id().agent_pubkey().clone(),
+        })
+        .await;
+    let mut sample_3: Actant = reinvite_record.entry().to_app_option().unwrap().unwrap();
+    sample_3.name = "Renamed by Bob again".to_string();
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: reinvite_record.signed_action.hashed.hash.clone(),
+          updated_actant: sample_3,
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn declined_invitation_is_no_longer_pending() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Alice creates a Actant listing Bob
+    let mut sample_1 = sample_actant_1(&conductors[0], &alice_zome).await;
+    sample_1.agents.push(bob_zome.cell_id().agent_pubkey().clone());
+    create_actant(&conductors[0], &alice_zome, sample_1).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let invitations: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_invitations", ())
+        .await;
+    assert_eq!(invitations.len(), 1);
+    let invitation_hash = invitations[0].signed_action.hashed.hash.clone();
+    let _: () = conductors[1]
+        .call(&bob_zome, "decline_invitation", invitation_hash.clone())
+        .await;
+    
+    let invitations: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_invitations", ())
+        .await;
+    assert!(invitations.is_empty());
+    
+    // The declined invitation can no longer be accepted
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "accept_invitation", invitation_hash)
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn only_admins_change_membership_and_roles() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    let alice_pub_key = alice_zome.cell_id().agent_pubkey().clone();
+    let bob_pub_key = bob_zome.cell_id().agent_pubkey().clone();
+    
+    // Alice creates a Actant with Bob as a plain member
+    let mut sample_1 = sample_actant_1(&conductors[0], &alice_zome).await;
+    sample_1.agents.push(bob_pub_key.clone());
+    let record: Record = create_actant(&conductors[0], &alice_zome, sample_1.clone()).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let invitations: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_invitations", ())
+        .await;
+    let _acceptance: Record = conductors[1]
+        .call(&bob_zome, "accept_invitation", invitations[0].signed_action.hashed.hash.clone())
+        .await;
+    
+    // Bob cannot remove Alice
+    let mut sample_2 = sample_1.clone();
+    sample_2.agents.retain(|agent| *agent != alice_pub_key);
+    sample_2.admins = vec![bob_pub_key.clone()];
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: original_action_hash.clone(),
+          updated_actant: sample_2,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Nor can he change what kind of Actant it is
+    let mut sample_2 = sample_1.clone();
+    sample_2.kind = ActantKind::Organisation;
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: original_action_hash.clone(),
+          previous_actant_hash: original_action_hash.clone(),
+          updated_actant: sample_2,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Alice cannot step down while she is the only admin
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "set_actant_role", SetActantRoleInput {
+          original_actant_hash: original_action_hash.clone(),
+          agent: alice_pub_key.clone(),
+          role: ActantRole::Member,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Alice promotes Bob and then steps down
+    let _update_record: Record = conductors[0]
+        .call(&alice_zome, "set_actant_role", SetActantRoleInput {
+          original_actant_hash: original_action_hash.clone(),
+          agent: bob_pub_key.clone(),
+          role: ActantRole::Admin,
+        })
+        .await;
+    let update_record: Record = conductors[0]
+        .call(&alice_zome, "set_actant_role", SetActantRoleInput {
+          original_actant_hash: original_action_hash.clone(),
+          agent: alice_pub_key.clone(),
+          role: ActantRole::Member,
+        })
+        .await;
+    let actant: Actant = update_record.entry().to_app_option().unwrap().unwrap();
+    assert_eq!(actant.role_of(&alice_pub_key), Some(ActantRole::Member));
+    assert_eq!(actant.role_of(&bob_pub_key), Some(ActantRole::Admin));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn guardian_is_checked_as_of_its_latest_revision() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (_bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    
+    // Alice's organisation can be the guardian of a river
+    let mut guardian = sample_actant_2(&conductors[0], &alice_zome).await;
+    guardian.kind = ActantKind::Organisation;
+    let guardian_hash = create_actant(&conductors[0], &alice_zome, guardian.clone()).await.signed_action.hashed.hash;
+    let mut river = sample_actant_1(&conductors[0], &alice_zome).await;
+    river.kind = ActantKind::MoreThanHuman;
+    river.guardians = vec![guardian_hash.clone()];
+    let record: Record = create_actant(&conductors[0], &alice_zome, river.clone()).await;
+    let actant: Actant = record.entry().to_app_option().unwrap().unwrap();
+    assert_eq!(actant.guardian_revision_hashes, vec![guardian_hash.clone()]);
+    
+    // Once it is itself registered as more-than-human, it can no longer guard another one
+    guardian.kind = ActantKind::MoreThanHuman;
+    let _revision: Record = conductors[0]
+        .call(&alice_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: guardian_hash.clone(),
+          previous_actant_hash: guardian_hash,
+          updated_actant: guardian,
+        })
+        .await;
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_actant", river)
+        .await;
+    assert!(result.is_err());
+}
@@ -4,10 +4,13 @@
 
 use hdk::prelude::*;
 use holochain::test_utils::consistency_10s;
+use holochain::conductor::api::error::ConductorApiResult;
 use holochain::{conductor::config::ConductorConfig, sweettest::*};
 
+use stewardship::pagination::{Page, PageInput, SortDirection};
+
 mod common;
-use common::{create_actant, sample_actant_1};
+use common::{create_actant, sample_actant_1, sample_actant_2};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn create_a_actant_and_get_all_actants() {
@@ -42,4 +45,67 @@ async fn create_a_actant_and_get_all_actants() {
     assert_eq!(get_records[0], record);    
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn create_actants_and_page_through_all_actants() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
 
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Alice creates three Actants
+    let record_1: Record = create_actant(&conductors[0], &alice_zome, sample_actant_1(&conductors[0], &alice_zome).await).await;
+    let record_2: Record = create_actant(&conductors[0], &alice_zome, sample_actant_2(&conductors[0], &alice_zome).await).await;
+    let record_3: Record = create_actant(&conductors[0], &alice_zome, sample_actant_1(&conductors[0], &alice_zome).await).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let page: Page = conductors[1]
+        .call(&bob_zome, "get_all_actants_page", PageInput {
+          page_size: 2,
+          after: None,
+          direction: SortDirection::Ascending,
+        })
+        .await;
+    assert_eq!(page.records, vec![record_1.clone(), record_2.clone()]);
+    assert!(page.next_cursor.is_some());
+    
+    let page: Page = conductors[1]
+        .call(&bob_zome, "get_all_actants_page", PageInput {
+          page_size: 2,
+          after: page.next_cursor,
+          direction: SortDirection::Ascending,
+        })
+        .await;
+    assert_eq!(page.records, vec![record_3.clone()]);
+    assert!(page.next_cursor.is_none());
+    
+    let page: Page = conductors[1]
+        .call(&bob_zome, "get_all_actants_page", PageInput {
+          page_size: 2,
+          after: None,
+          direction: SortDirection::Descending,
+        })
+        .await;
+    assert_eq!(page.records, vec![record_3, record_2]);
+    
+    // An empty page could never point to the next one
+    let result: ConductorApiResult<Page> = conductors[1]
+        .call_fallible(&bob_zome, "get_all_actants_page", PageInput {
+          page_size: 0,
+          after: None,
+          direction: SortDirection::Ascending,
+        })
+        .await;
+    assert!(result.is_err());
+}
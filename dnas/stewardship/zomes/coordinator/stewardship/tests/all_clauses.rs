@@ -6,8 +6,12 @@ use hdk::prelude::*;
 use holochain::test_utils::consistency_10s;
 use holochain::{conductor::config::ConductorConfig, sweettest::*};
 
+use stewardship_integrity::*;
+
+use stewardship::time_shard::TimeRange;
+
 mod common;
-use common::{create_clause, sample_clause_1};
+use common::{activate_clause, create_clause, sample_clause_1, sample_clause_2};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn create_a_clause_and_get_all_clauses() {
@@ -42,4 +46,82 @@ async fn create_a_clause_and_get_all_clauses() {
     assert_eq!(get_records[0], record);    
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn create_a_clause_and_get_clauses_in_range() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let sample = sample_clause_1(&conductors[0], &alice_zome).await;
+    
+    // Alice creates a Clause
+    let record: Record = create_clause(&conductors[0], &alice_zome, sample.clone()).await;
+    let created_at = record.action().timestamp();
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let one_day = std::time::Duration::from_secs(24 * 60 * 60);
+    let get_records: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_clauses_in_range", TimeRange {
+          from: (created_at - one_day).unwrap(),
+          to: (created_at + one_day).unwrap(),
+        })
+        .await;
+    assert_eq!(get_records, vec![record]);
+    
+    let get_records: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_clauses_in_range", TimeRange {
+          from: (created_at - one_day * 3).unwrap(),
+          to: (created_at - one_day * 2).unwrap(),
+        })
+        .await;
+    assert!(get_records.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_all_clauses_filtered_by_status() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
 
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Alice creates two clauses and activates only the first one
+    let active_record: Record = create_clause(&conductors[0], &alice_zome, sample_clause_1(&conductors[0], &alice_zome).await).await;
+    let draft_record: Record = create_clause(&conductors[0], &alice_zome, sample_clause_2(&conductors[0], &alice_zome).await).await;
+    activate_clause(&conductors[0], &alice_zome, active_record.signed_action.hashed.hash.clone()).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let active_records: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_all_clauses", Some(ClauseStatus::Active))
+        .await;
+    assert_eq!(active_records, vec![active_record]);
+    
+    let draft_records: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_all_clauses", Some(ClauseStatus::Draft))
+        .await;
+    assert_eq!(draft_records, vec![draft_record]);
+}
@@ -2,16 +2,30 @@
 #![allow(unused_variables)]
 #![allow(unused_imports)]
 
+use futures::StreamExt;
 use hdk::prelude::*;
 use holochain::test_utils::consistency_10s;
+use holochain::conductor::api::error::ConductorApiResult;
 use holochain::{conductor::config::ConductorConfig, sweettest::*};
 
 use stewardship_integrity::*;
 
+use stewardship::clause::{
+    AddClauseForActantInput, ClauseForActant, GetClausesForActantInput, UpdateClauseInput,
+};
+use stewardship::clause_approval::{
+    AddApprovalForClauseAmendmentInput, ApproveClauseAmendmentInput, FinalizeClauseAmendmentInput,
+};
+use stewardship::clause_ratification::{CommitClauseRatificationInput, RequestClauseRatificationInput};
+use stewardship::clause_transition::TransitionClauseInput;
+use stewardship::pagination::{PageInput, SortDirection};
+use stewardship::reporting_schedule::{OverdueObligation, OverdueObligationsPage};
 
 mod common;
 use common::{create_clause, sample_clause_1, sample_clause_2};
 
+use common::{create_report, sample_report_for_clause};
+
 use common::{create_actant, sample_actant_1, sample_actant_2};
 
 #[tokio::test(flavor = "multi_thread")]
@@ -110,3 +124,666 @@ async fn create_and_delete_clause() {
         
     assert!(get_record.is_none());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn clause_with_invalid_right_holders_is_rejected() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (_bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    
+    let sample = sample_clause_1(&conductors[0], &alice_zome).await;
+    
+    // A right holder that does not resolve to an Actant
+    let mut clause = sample.clone();
+    clause.right_holders = vec![::fixt::fixt!(ActionHash)];
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_clause", clause)
+        .await;
+    assert!(result.is_err());
+    
+    // No right holders at all
+    let mut clause = sample.clone();
+    clause.right_holders = vec![];
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_clause", clause)
+        .await;
+    assert!(result.is_err());
+    
+    // The same right holder listed twice
+    let mut clause = sample.clone();
+    clause.right_holders = vec![sample.right_holders[0].clone(), sample.right_holders[0].clone()];
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_clause", clause)
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn clause_amendment_needs_every_responsibility_holder() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let alice_actant_hash = create_actant(&conductors[0], &alice_zome, sample_actant_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+    let bob_actant_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let mut sample = sample_clause_1(&conductors[0], &alice_zome).await;
+    sample.responsibilty_holders = vec![alice_actant_hash.clone(), bob_actant_hash.clone()];
+    
+    // Alice creates a Clause
+    let record: Record = create_clause(&conductors[0], &alice_zome, sample.clone()).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+    
+    // Alice proposes an amendment
+    let mut amended = sample.clone();
+    amended.statement = "Lorem ipsum amended".to_string();
+    let input = UpdateClauseInput {
+      original_clause_hash: original_action_hash.clone(),
+      previous_clause_hash: original_action_hash.clone(),
+      updated_clause: amended.clone(),
+    };
+    let revision_record: Record = conductors[0]
+        .call(&alice_zome, "propose_clause_amendment", input)
+        .await;
+    let revision_hash = revision_record.signed_action.hashed.hash.clone();
+    
+    // Alice approves it on behalf of her Actant
+    let alice_approval: Record = conductors[0]
+        .call(&alice_zome, "approve_clause_amendment", ApproveClauseAmendmentInput {
+          original_clause_hash: original_action_hash.clone(),
+          clause_revision_hash: revision_hash.clone(),
+          actant_hash: alice_actant_hash.clone(),
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob's Actant has not approved yet
+    let get_record: Option<Record> = conductors[1]
+        .call(&bob_zome, "get_clause", original_action_hash.clone())
+        .await;
+    assert_eq!(record, get_record.unwrap());
+    
+    // Bob cannot approve on behalf of Alice's Actant
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "approve_clause_amendment", ApproveClauseAmendmentInput {
+          original_clause_hash: original_action_hash.clone(),
+          clause_revision_hash: revision_hash.clone(),
+          actant_hash: alice_actant_hash.clone(),
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Nor link Alice's approval to the amendment himself
+    let result: ConductorApiResult<()> = conductors[1]
+        .call_fallible(&bob_zome, "add_approval_for_clause_amendment", AddApprovalForClauseAmendmentInput {
+          base_clause_revision_hash: revision_hash.clone(),
+          target_clause_approval_hash: alice_approval.signed_action.hashed.hash.clone(),
+        })
+        .await;
+    assert!(result.is_err());
+    
+    let _approval: Record = conductors[1]
+        .call(&bob_zome, "approve_clause_amendment", ApproveClauseAmendmentInput {
+          original_clause_hash: original_action_hash.clone(),
+          clause_revision_hash: revision_hash.clone(),
+          actant_hash: bob_actant_hash.clone(),
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let get_record: Option<Record> = conductors[0]
+        .call(&alice_zome, "get_clause", original_action_hash.clone())
+        .await;
+    assert_eq!(revision_record, get_record.unwrap());
+    
+    let history: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_clause_history", original_action_hash.clone())
+        .await;
+    assert_eq!(history, vec![record.clone(), revision_record.clone()]);
+    
+    // Amendments can only build on the current version of the clause
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "propose_clause_amendment", UpdateClauseInput {
+          original_clause_hash: original_action_hash.clone(),
+          previous_clause_hash: original_action_hash.clone(),
+          updated_clause: amended.clone(),
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Finalizing an accepted amendment again is a no-op
+    let accepted: bool = conductors[0]
+        .call(&alice_zome, "finalize_clause_amendment", FinalizeClauseAmendmentInput {
+          original_clause_hash: original_action_hash.clone(),
+          clause_revision_hash: revision_hash.clone(),
+        })
+        .await;
+    assert!(accepted);
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let history: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_clause_history", original_action_hash.clone())
+        .await;
+    assert_eq!(history, vec![record, revision_record]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_clauses_for_actant_by_role() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let sample = sample_clause_1(&conductors[0], &alice_zome).await;
+    let right_holder_hash = sample.right_holders[0].clone();
+    
+    // Alice creates a Clause
+    let record: Record = create_clause(&conductors[0], &alice_zome, sample.clone()).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let clauses: Vec<ClauseForActant> = conductors[1]
+        .call(&bob_zome, "get_clauses_for_actant", GetClausesForActantInput {
+          actant_hash: right_holder_hash.clone(),
+          role: None,
+        })
+        .await;
+    assert_eq!(clauses, vec![ClauseForActant { role: ClauseRole::RightHolder, record: record.clone() }]);
+    
+    let clauses: Vec<ClauseForActant> = conductors[1]
+        .call(&bob_zome, "get_clauses_for_actant", GetClausesForActantInput {
+          actant_hash: right_holder_hash.clone(),
+          role: Some(ClauseRole::ResponsibilityHolder),
+        })
+        .await;
+    assert!(clauses.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn actant_cannot_link_to_clause_it_does_not_hold_in_that_role() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (_bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    
+    let sample = sample_clause_1(&conductors[0], &alice_zome).await;
+    let right_holder_hash = sample.right_holders[0].clone();
+    let clause_hash = create_clause(&conductors[0], &alice_zome, sample).await.signed_action.hashed.hash;
+    let outsider_hash = create_actant(&conductors[0], &alice_zome, sample_actant_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+    
+    // An Actant that holds no role in the clause cannot list it
+    let result: ConductorApiResult<()> = conductors[0]
+        .call_fallible(&alice_zome, "add_clause_for_actant", AddClauseForActantInput {
+          base_actant_hash: outsider_hash,
+          target_clause_hash: clause_hash.clone(),
+          role: ClauseRole::RightHolder,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Nor can the right holder list it as one of its responsibilities
+    let result: ConductorApiResult<()> = conductors[0]
+        .call_fallible(&alice_zome, "add_clause_for_actant", AddClauseForActantInput {
+          base_actant_hash: right_holder_hash.clone(),
+          target_clause_hash: clause_hash.clone(),
+          role: ClauseRole::ResponsibilityHolder,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // In the role it holds it can
+    let result: ConductorApiResult<()> = conductors[0]
+        .call_fallible(&alice_zome, "add_clause_for_actant", AddClauseForActantInput {
+          base_actant_hash: right_holder_hash,
+          target_clause_hash: clause_hash,
+          role: ClauseRole::RightHolder,
+        })
+        .await;
+    assert!(result.is_ok());
+}
+
+fn accepted(acceptance: PreflightRequestAcceptance) -> PreflightResponse {
+    match acceptance {
+        PreflightRequestAcceptance::Accepted(response) => response,
+        _ => panic!("The ratification request was not accepted"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn only_the_allowed_holders_move_a_clause_along() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Bob's Actant holds the rights, Alice's the responsibilities
+    let alice_actant_hash = create_actant(&conductors[0], &alice_zome, sample_actant_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+    let bob_actant_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let mut clause = sample_clause_1(&conductors[0], &alice_zome).await;
+    clause.right_holders = vec![bob_actant_hash];
+    clause.responsibilty_holders = vec![alice_actant_hash.clone()];
+    let draft_hash = create_clause(&conductors[0], &alice_zome, clause.clone()).await.signed_action.hashed.hash;
+    let proposed_hash = create_clause(&conductors[0], &alice_zome, clause).await.signed_action.hashed.hash;
+    let _proposed: Record = conductors[0]
+        .call(&alice_zome, "transition_clause", TransitionClauseInput {
+          clause_hash: proposed_hash.clone(),
+          to: ClauseStatus::Proposed,
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob is not a responsibility holder, so he cannot activate the proposed clause
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "transition_clause", TransitionClauseInput {
+          clause_hash: proposed_hash,
+          to: ClauseStatus::Active,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Nor can he terminate a draft, which was never active
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "transition_clause", TransitionClauseInput {
+          clause_hash: draft_hash,
+          to: ClauseStatus::Terminated,
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+/// Creates a clause in ratified mode between Alice's and Bob's Actants and proposes it
+async fn propose_ratified_clause(
+    conductors: &SweetConductorBatch,
+    alice: &SweetCell,
+    bobbo: &SweetCell,
+) -> ActionHash {
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let alice_actant_hash = create_actant(&conductors[0], &alice_zome, sample_actant_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+    let bob_actant_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([alice, bobbo]).await;
+    
+    let mut sample = sample_clause_1(&conductors[0], &alice_zome).await;
+    sample.right_holders = vec![bob_actant_hash];
+    sample.responsibilty_holders = vec![alice_actant_hash];
+    sample.requires_ratification = true;
+    let clause_hash = create_clause(&conductors[0], &alice_zome, sample).await.signed_action.hashed.hash;
+    let _proposed: Record = conductors[0]
+        .call(&alice_zome, "transition_clause", TransitionClauseInput {
+          clause_hash: clause_hash.clone(),
+          to: ClauseStatus::Proposed,
+        })
+        .await;
+    
+    consistency_10s([alice, bobbo]).await;
+    
+    clause_hash
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ratified_clause_is_activated_after_countersigning() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let clause_hash = propose_ratified_clause(&conductors, &alice, &bobbo).await;
+    
+    // Without a ratification the clause cannot be activated
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "transition_clause", TransitionClauseInput {
+          clause_hash: clause_hash.clone(),
+          to: ClauseStatus::Active,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Alice and Bob countersign the ratification
+    let request: PreflightRequest = conductors[0]
+        .call(&alice_zome, "request_clause_ratification", RequestClauseRatificationInput {
+          clause_hash: clause_hash.clone(),
+          signing_agents: vec![
+            alice_zome.cell_id().agent_pubkey().clone(),
+            bob_zome.cell_id().agent_pubkey().clone(),
+          ],
+          session_length_ms: 30_000,
+        })
+        .await;
+    let alice_acceptance: PreflightRequestAcceptance = conductors[0]
+        .call(&alice_zome, "accept_clause_ratification", request.clone())
+        .await;
+    let bob_acceptance: PreflightRequestAcceptance = conductors[1]
+        .call(&bob_zome, "accept_clause_ratification", request)
+        .await;
+    let responses = vec![accepted(alice_acceptance), accepted(bob_acceptance)];
+    let _alice_ratification: ActionHash = conductors[0]
+        .call(&alice_zome, "commit_clause_ratification", CommitClauseRatificationInput {
+          clause_hash: clause_hash.clone(),
+          responses: responses.clone(),
+        })
+        .await;
+    let _bob_ratification: ActionHash = conductors[1]
+        .call(&bob_zome, "commit_clause_ratification", CommitClauseRatificationInput {
+          clause_hash: clause_hash.clone(),
+          responses,
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob links his ratification from the clause once his chain unlocks
+    let mut linked = false;
+    for _ in 0..10 {
+        let result: ConductorApiResult<ActionHash> = conductors[1]
+            .call_fallible(&bob_zome, "link_clause_ratification", clause_hash.clone())
+            .await;
+        if result.is_ok() {
+            linked = true;
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
+    assert!(linked);
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // The ratification is found before the clause is activated with it
+    let ratification: Option<Record> = conductors[0]
+        .call(&alice_zome, "get_clause_ratification", clause_hash.clone())
+        .await;
+    assert!(matches!(ratification.unwrap().entry().as_option(), Some(Entry::CounterSign(_, _))));
+    
+    // Alice's chain unlocks once the session completes
+    let mut activated = false;
+    for _ in 0..10 {
+        let result: ConductorApiResult<Record> = conductors[0]
+            .call_fallible(&alice_zome, "transition_clause", TransitionClauseInput {
+              clause_hash: clause_hash.clone(),
+              to: ClauseStatus::Active,
+            })
+            .await;
+        if result.is_ok() {
+            activated = true;
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
+    assert!(activated);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ratification_session_times_out_without_every_signature() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let clause_hash = propose_ratified_clause(&conductors, &alice, &bobbo).await;
+    
+    let request: PreflightRequest = conductors[0]
+        .call(&alice_zome, "request_clause_ratification", RequestClauseRatificationInput {
+          clause_hash: clause_hash.clone(),
+          signing_agents: vec![
+            alice_zome.cell_id().agent_pubkey().clone(),
+            bob_zome.cell_id().agent_pubkey().clone(),
+          ],
+          session_length_ms: 5_000,
+        })
+        .await;
+    let alice_acceptance: PreflightRequestAcceptance = conductors[0]
+        .call(&alice_zome, "accept_clause_ratification", request.clone())
+        .await;
+    let bob_acceptance: PreflightRequestAcceptance = conductors[1]
+        .call(&bob_zome, "accept_clause_ratification", request)
+        .await;
+    
+    // Only Alice commits, so the session never completes
+    let _alice_ratification: ActionHash = conductors[0]
+        .call(&alice_zome, "commit_clause_ratification", CommitClauseRatificationInput {
+          clause_hash: clause_hash.clone(),
+          responses: vec![accepted(alice_acceptance), accepted(bob_acceptance)],
+        })
+        .await;
+    
+    // Alice's chain stays locked while the session is open
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_actant", sample_actant_2(&conductors[0], &alice_zome).await)
+        .await;
+    assert!(result.is_err());
+    
+    tokio::time::sleep(std::time::Duration::from_secs(8)).await;
+    
+    // The session was abandoned: the chain is unlocked and the clause is still unratified
+    let _record: Record = create_actant(&conductors[0], &alice_zome, sample_actant_2(&conductors[0], &alice_zome).await).await;
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "transition_clause", TransitionClauseInput {
+          clause_hash: clause_hash.clone(),
+          to: ClauseStatus::Active,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    let ratification: Option<Record> = conductors[0]
+        .call(&alice_zome, "get_clause_ratification", clause_hash)
+        .await;
+    assert!(ratification.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn endorsement_quorum_is_bounded_by_right_holders() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    
+    let sample = sample_clause_1(&conductors[0], &alice_zome).await;
+    
+    let mut no_quorum = sample.clone();
+    no_quorum.endorsement_quorum = 0;
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_clause", no_quorum)
+        .await;
+    assert!(result.is_err());
+    
+    let mut unreachable_quorum = sample.clone();
+    unreachable_quorum.endorsement_quorum = 2;
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_clause", unreachable_quorum)
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn responsibility_holder_without_report_is_overdue() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let bob_holder_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Reports are due hourly and the schedule started two hours ago
+    let mut clause = sample_clause_1(&conductors[0], &alice_zome).await;
+    let alice_holder_hash = clause.responsibilty_holders[0].clone();
+    clause.responsibilty_holders.push(bob_holder_hash.clone());
+    clause.reporting_schedule = Some(ReportingSchedule {
+      start: Timestamp::from_micros(Timestamp::now().as_micros() - 2 * 3600 * 1_000_000),
+      interval_secs: 3600,
+      grace_period_secs: 0,
+    });
+    
+    let mut invalid = clause.clone();
+    invalid.reporting_schedule = Some(ReportingSchedule {
+      start: Timestamp::now(),
+      interval_secs: 3600,
+      grace_period_secs: 3600,
+    });
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_clause", invalid)
+        .await;
+    assert!(result.is_err());
+    
+    // Alice reports for her Actant, Bob does not
+    let report = sample_report_for_clause(&conductors[0], &alice_zome, clause).await;
+    create_report(&conductors[0], &alice_zome, report.clone()).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let obligations: Vec<OverdueObligation> = conductors[1]
+        .call(&bob_zome, "get_overdue_obligations", None::<ActionHash>)
+        .await;
+    assert_eq!(obligations.len(), 1);
+    assert_eq!(obligations[0].clause_hash, report.clause_hash);
+    assert_eq!(obligations[0].actant_hash, bob_holder_hash);
+    assert_eq!(obligations[0].last_report_at, None);
+    
+    // The same obligation is found by checking all clauses one page at a time
+    let page: OverdueObligationsPage = conductors[1]
+        .call(&bob_zome, "get_overdue_obligations_page", PageInput {
+          page_size: 10,
+          after: None,
+          direction: SortDirection::Descending,
+        })
+        .await;
+    assert_eq!(page.obligations, obligations);
+    assert!(page.next_cursor.is_none());
+    
+    let obligations: Vec<OverdueObligation> = conductors[0]
+        .call(&alice_zome, "get_overdue_obligations", Some(alice_holder_hash))
+        .await;
+    assert!(obligations.is_empty());
+    
+    // Running Bob's scheduled check signals his overdue obligation
+    let mut signals = Box::pin(conductors[1].signals());
+    let _next_schedule: Option<Schedule> = conductors[1]
+        .call(&bob_zome, "check_my_overdue_obligations", None::<Schedule>)
+        .await;
+    let signalled = tokio::time::timeout(std::time::Duration::from_secs(10), async {
+        while let Some(signal) = signals.next().await {
+            if let holochain::prelude::Signal::App(_, app_signal) = signal {
+                if let Ok(stewardship::Signal::OverdueObligations { obligations }) = app_signal
+                    .into_inner()
+                    .decode::<stewardship::Signal>()
+                {
+                    return obligations;
+                }
+            }
+        }
+        vec![]
+    })
+        .await
+        .unwrap();
+    assert_eq!(signalled.len(), 1);
+    assert_eq!(signalled[0].actant_hash, bob_holder_hash);
+}
@@ -3,19 +3,29 @@ use holochain::sweettest::*;
 
 use stewardship_integrity::*;
 
+use stewardship::clause_transition::TransitionClauseInput;
+
 
 
 pub async fn sample_actant_1(conductor: &SweetConductor, zome: &SweetZome) -> Actant {
     Actant {
-	  agents: vec![::fixt::fixt!(AgentPubKey)],
+	  agents: vec![zome.cell_id().agent_pubkey().clone()],
+	  admins: vec![zome.cell_id().agent_pubkey().clone()],
 	  name: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
+	  kind: ActantKind::Human,
+	  guardians: vec![],
+	  guardian_revision_hashes: vec![],
     }
 }
 
 pub async fn sample_actant_2(conductor: &SweetConductor, zome: &SweetZome) -> Actant {
     Actant {
-	  agents: vec![::fixt::fixt!(AgentPubKey)],
+	  agents: vec![zome.cell_id().agent_pubkey().clone()],
+	  admins: vec![zome.cell_id().agent_pubkey().clone()],
 	  name: "Lorem ipsum 2".to_string(),
+	  kind: ActantKind::Human,
+	  guardians: vec![],
+	  guardian_revision_hashes: vec![],
     }
 }
 
@@ -30,17 +40,25 @@ pub async fn create_actant(conductor: &SweetConductor, zome: &SweetZome, actant:
 
 pub async fn sample_clause_1(conductor: &SweetConductor, zome: &SweetZome) -> Clause {
     Clause {
+	  title: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
 	  statement: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
-	  right_holders: vec![::fixt::fixt!(ActionHash)],
+	  right_holders: vec![create_actant(conductor, zome, sample_actant_2(conductor, zome).await).await.signed_action.hashed.hash],
           responsibilty_holders: vec![create_actant(conductor, zome, sample_actant_1(conductor, zome).await).await.signed_action.hashed.hash],
+          requires_ratification: false,
+          endorsement_quorum: 1,
+          reporting_schedule: None,
     }
 }
 
 pub async fn sample_clause_2(conductor: &SweetConductor, zome: &SweetZome) -> Clause {
     Clause {
+	  title: "Lorem ipsum 2".to_string(),
 	  statement: "Lorem ipsum 2".to_string(),
-	  right_holders: vec![::fixt::fixt!(ActionHash)],
+	  right_holders: vec![create_actant(conductor, zome, sample_actant_1(conductor, zome).await).await.signed_action.hashed.hash],
           responsibilty_holders: vec![create_actant(conductor, zome, sample_actant_2(conductor, zome).await).await.signed_action.hashed.hash],
+          requires_ratification: false,
+          endorsement_quorum: 1,
+          reporting_schedule: None,
     }
 }
 
@@ -53,22 +71,45 @@ pub async fn create_clause(conductor: &SweetConductor, zome: &SweetZome, clause:
 
 
 
-pub async fn sample_report_1(conductor: &SweetConductor, zome: &SweetZome) -> Report {
+/// Proposes and activates a clause, returning the activating transition
+pub async fn activate_clause(conductor: &SweetConductor, zome: &SweetZome, clause_hash: ActionHash) -> ActionHash {
+    let _proposed: Record = conductor
+        .call(zome, "transition_clause", TransitionClauseInput {
+          clause_hash: clause_hash.clone(),
+          to: ClauseStatus::Proposed,
+        })
+        .await;
+    let active: Record = conductor
+        .call(zome, "transition_clause", TransitionClauseInput {
+          clause_hash,
+          to: ClauseStatus::Active,
+        })
+        .await;
+    active.signed_action.hashed.hash
+}
+
+pub async fn sample_report_for_clause(conductor: &SweetConductor, zome: &SweetZome, clause: Clause) -> Report {
+    let actant_hash = clause.responsibilty_holders[0].clone();
+    let content = clause.statement.clone();
+    let clause_hash = create_clause(conductor, zome, clause).await.signed_action.hashed.hash;
+    let transition_hash = activate_clause(conductor, zome, clause_hash.clone()).await;
     Report {
-	  report_type: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
-	  content: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
-          actant_hash: create_actant(conductor, zome, sample_actant_1(conductor, zome).await).await.signed_action.hashed.hash,
-          clause_hash: create_clause(conductor, zome, sample_clause_1(conductor, zome).await).await.signed_action.hashed.hash,
+	  report_type: ReportType::Progress,
+	  content,
+          actant_hash: actant_hash.clone(),
+          clause_hash,
+          actant_revision_hash: Some(actant_hash),
+          clause_transition_hash: Some(transition_hash),
+          guardian_revision_hashes: vec![],
     }
 }
 
+pub async fn sample_report_1(conductor: &SweetConductor, zome: &SweetZome) -> Report {
+    sample_report_for_clause(conductor, zome, sample_clause_1(conductor, zome).await).await
+}
+
 pub async fn sample_report_2(conductor: &SweetConductor, zome: &SweetZome) -> Report {
-    Report {
-	  report_type: "Lorem ipsum 2".to_string(),
-	  content: "Lorem ipsum 2".to_string(),
-          actant_hash: create_actant(conductor, zome, sample_actant_2(conductor, zome).await).await.signed_action.hashed.hash,
-          clause_hash: create_clause(conductor, zome, sample_clause_2(conductor, zome).await).await.signed_action.hashed.hash,
-    }
+    sample_report_for_clause(conductor, zome, sample_clause_2(conductor, zome).await).await
 }
 
 pub async fn create_report(conductor: &SweetConductor, zome: &SweetZome, report: Report) -> Record {
@@ -80,15 +121,30 @@ pub async fn create_report(conductor: &SweetConductor, zome: &SweetZome, report:
 
 
 
-pub async fn sample_endorsement_1(conductor: &SweetConductor, zome: &SweetZome) -> Endorsement {
+/// Creates a report by the agent of `zome` on a clause whose right holder is `right_holder_hash`
+pub async fn sample_endorsement_1(conductor: &SweetConductor, zome: &SweetZome, right_holder_hash: ActionHash) -> Endorsement {
+    let mut clause = sample_clause_1(conductor, zome).await;
+    clause.right_holders = vec![right_holder_hash];
     Endorsement {
-          report_hash: create_report(conductor, zome, sample_report_1(conductor, zome).await).await.signed_action.hashed.hash,
+          report_hash: create_report(conductor, zome, sample_report_for_clause(conductor, zome, clause).await).await.signed_action.hashed.hash,
+          stance: EndorsementStance::Endorse,
+          comment: None,
+          confidence: 90,
+          actant_hash: None,
+          actant_revision_hashes: vec![],
     }
 }
 
-pub async fn sample_endorsement_2(conductor: &SweetConductor, zome: &SweetZome) -> Endorsement {
+pub async fn sample_endorsement_2(conductor: &SweetConductor, zome: &SweetZome, right_holder_hash: ActionHash) -> Endorsement {
+    let mut clause = sample_clause_2(conductor, zome).await;
+    clause.right_holders = vec![right_holder_hash];
     Endorsement {
-          report_hash: create_report(conductor, zome, sample_report_2(conductor, zome).await).await.signed_action.hashed.hash,
+          report_hash: create_report(conductor, zome, sample_report_for_clause(conductor, zome, clause).await).await.signed_action.hashed.hash,
+          stance: EndorsementStance::PartiallyEndorse,
+          comment: Some("Progress is slower than reported".to_string()),
+          confidence: 60,
+          actant_hash: None,
+          actant_revision_hashes: vec![],
     }
 }
 
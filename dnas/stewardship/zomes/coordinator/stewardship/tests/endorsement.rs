@@ -2,17 +2,30 @@
 #![allow(unused_variables)]
 #![allow(unused_imports)]
 
+use futures::StreamExt;
 use hdk::prelude::*;
 use holochain::test_utils::consistency_10s;
+use holochain::conductor::api::error::ConductorApiResult;
 use holochain::{conductor::config::ConductorConfig, sweettest::*};
 
 use stewardship_integrity::*;
 
+use stewardship::endorsement::{
+    AddEndorsementForReportInput, EndorsementStanceCounts, EndorsementWithRevocation,
+    GetEndorsementsForReportInput, RevokeEndorsementInput,
+};
+use stewardship::actant::UpdateActantInput;
+use stewardship::report_status::{ReportStatus, VerificationStatus};
+
 
 mod common;
 use common::{create_endorsement, sample_endorsement_1, sample_endorsement_2};
 
-use common::{create_report, sample_report_1, sample_report_2};
+use common::{create_report, sample_report_1, sample_report_2, sample_report_for_clause};
+
+use common::{create_clause, sample_clause_1};
+
+use common::{create_actant, sample_actant_2};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn create_endorsement_test() {
@@ -27,14 +40,22 @@ async fn create_endorsement_test() {
     let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
     conductors.exchange_peer_info().await;
 
-    let ((alice,), (_bobbo,)) = apps.into_tuples();
+    let ((alice,), (bobbo,)) = apps.into_tuples();
     
     let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Bob creates the right holder, so he is a member of it
+    let right_holder_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
     
-    let sample = sample_endorsement_1(&conductors[0], &alice_zome).await;
+    let sample = sample_endorsement_1(&conductors[0], &alice_zome, right_holder_hash).await;
     
-    // Alice creates a Endorsement
-    let record: Record = create_endorsement(&conductors[0], &alice_zome, sample.clone()).await;
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob creates a Endorsement
+    let record: Record = create_endorsement(&conductors[1], &bob_zome, sample.clone()).await;
     let entry: Endorsement = record.entry().to_app_option().unwrap().unwrap();
     assert!(entry.eq(&sample));
 }
@@ -58,18 +79,596 @@ async fn create_and_read_endorsement() {
     let alice_zome = alice.zome("stewardship");
     let bob_zome = bobbo.zome("stewardship");
     
-    let sample = sample_endorsement_1(&conductors[0], &alice_zome).await;
+    // Bob creates the right holder, so he is a member of it
+    let right_holder_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
     
-    // Alice creates a Endorsement
-    let record: Record = create_endorsement(&conductors[0], &alice_zome, sample.clone()).await;
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let sample = sample_endorsement_1(&conductors[0], &alice_zome, right_holder_hash).await;
     
     consistency_10s([&alice, &bobbo]).await;
     
-    let get_record: Option<Record> = conductors[1]
-        .call(&bob_zome, "get_endorsement", record.signed_action.action_address().clone())
+    // Bob creates a Endorsement
+    let record: Record = create_endorsement(&conductors[1], &bob_zome, sample.clone()).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let get_record: Option<Record> = conductors[0]
+        .call(&alice_zome, "get_endorsement", record.signed_action.action_address().clone())
         .await;
         
     assert_eq!(record, get_record.unwrap());    
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn author_cannot_endorse_own_report() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (_bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    
+    // Alice is both the reporter and an agent of the right holder
+    let right_holder_hash = create_actant(&conductors[0], &alice_zome, sample_actant_2(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+    let sample = sample_endorsement_1(&conductors[0], &alice_zome, right_holder_hash).await;
+    
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_endorsement", sample)
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn non_right_holder_cannot_endorse_report() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // The right holder's only agent is Alice
+    let right_holder_hash = create_actant(&conductors[0], &alice_zome, sample_actant_2(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+    let sample = sample_endorsement_1(&conductors[0], &alice_zome, right_holder_hash).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_endorsement", sample)
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn report_cannot_link_to_another_reports_endorsement() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Bob endorses two of Alice's reports for the right holder he created
+    let right_holder_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let sample_1 = sample_endorsement_1(&conductors[0], &alice_zome, right_holder_hash.clone()).await;
+    let sample_2 = sample_endorsement_2(&conductors[0], &alice_zome, right_holder_hash).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let endorsement_1_hash = create_endorsement(&conductors[1], &bob_zome, sample_1.clone()).await.signed_action.hashed.hash;
+    let endorsement_2_hash = create_endorsement(&conductors[1], &bob_zome, sample_2).await.signed_action.hashed.hash;
+    
+    // The first report cannot list the endorsement of the second one
+    let result: ConductorApiResult<()> = conductors[1]
+        .call_fallible(&bob_zome, "add_endorsement_for_report", AddEndorsementForReportInput {
+          base_report_hash: sample_1.report_hash.clone(),
+          target_endorsement_hash: endorsement_2_hash,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Nor can anyone but Bob link his endorsement to the report it is about
+    let result: ConductorApiResult<()> = conductors[0]
+        .call_fallible(&alice_zome, "add_endorsement_for_report", AddEndorsementForReportInput {
+          base_report_hash: sample_1.report_hash.clone(),
+          target_endorsement_hash: endorsement_1_hash,
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn guardian_member_can_endorse_for_more_than_human_right_holder() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(3, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
 
+    let ((alice,), (bobbo,), (carol,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    let carol_zome = carol.zome("stewardship");
+    
+    // Bob's organisation is the guardian of a river that holds the rights of Alice's clause
+    let mut guardian = sample_actant_2(&conductors[1], &bob_zome).await;
+    guardian.kind = ActantKind::Organisation;
+    let guardian_hash = create_actant(&conductors[1], &bob_zome, guardian.clone()).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let mut river = sample_actant_2(&conductors[0], &alice_zome).await;
+    river.kind = ActantKind::MoreThanHuman;
+    river.guardians = vec![guardian_hash.clone()];
+    let river_hash = create_actant(&conductors[0], &alice_zome, river).await.signed_action.hashed.hash;
+    let mut sample = sample_endorsement_1(&conductors[0], &alice_zome, river_hash.clone()).await;
+    sample.actant_hash = Some(river_hash);
+    
+    // Carol only joins the guardian in a later revision of it
+    guardian.agents.push(carol_zome.cell_id().agent_pubkey().clone());
+    let _revision: Record = conductors[1]
+        .call(&bob_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: guardian_hash.clone(),
+          previous_actant_hash: guardian_hash,
+          updated_actant: guardian,
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let invitations: Vec<Record> = conductors[2]
+        .call(&carol_zome, "get_my_invitations", ())
+        .await;
+    let _acceptance: Record = conductors[2]
+        .call(&carol_zome, "accept_invitation", invitations[0].signed_action.hashed.hash.clone())
+        .await;
+    
+    // Carol endorses for the river as of the guardian revision she is listed in
+    let record: Record = create_endorsement(&conductors[2], &carol_zome, sample.clone()).await;
+    let endorsement: Endorsement = record.entry().to_app_option().unwrap().unwrap();
+    assert_eq!(endorsement.report_hash, sample.report_hash);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn agent_can_endorse_report_only_once() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Bob creates the right holder, so he is a member of it
+    let right_holder_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let sample = sample_endorsement_1(&conductors[0], &alice_zome, right_holder_hash).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob endorses the report
+    create_endorsement(&conductors[1], &bob_zome, sample.clone()).await;
+    
+    // Bob endorses the report again
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_endorsement", sample.clone())
+        .await;
+    assert!(result.is_err());
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let endorsements: Vec<EndorsementWithRevocation> = conductors[0]
+        .call(&alice_zome, "get_endorsements_for_report", GetEndorsementsForReportInput {
+          report_hash: sample.report_hash.clone(),
+          include_revoked: false,
+        })
+        .await;
+    assert_eq!(endorsements.len(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn revoked_endorsement_is_excluded_unless_requested() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Bob creates the right holder, so he is a member of it
+    let right_holder_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let sample = sample_endorsement_1(&conductors[0], &alice_zome, right_holder_hash).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let endorsement = create_endorsement(&conductors[1], &bob_zome, sample.clone()).await;
+    let endorsement_hash = endorsement.signed_action.action_address().clone();
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Only the endorser can revoke its endorsement
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "revoke_endorsement", RevokeEndorsementInput {
+          endorsement_hash: endorsement_hash.clone(),
+          reason: None,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    let revocation: Record = conductors[1]
+        .call(&bob_zome, "revoke_endorsement", RevokeEndorsementInput {
+          endorsement_hash: endorsement_hash.clone(),
+          reason: Some("The report turned out to be false".to_string()),
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let endorsements: Vec<EndorsementWithRevocation> = conductors[0]
+        .call(&alice_zome, "get_endorsements_for_report", GetEndorsementsForReportInput {
+          report_hash: sample.report_hash.clone(),
+          include_revoked: false,
+        })
+        .await;
+    assert!(endorsements.is_empty());
+    
+    let endorsements: Vec<EndorsementWithRevocation> = conductors[0]
+        .call(&alice_zome, "get_endorsements_for_report", GetEndorsementsForReportInput {
+          report_hash: sample.report_hash.clone(),
+          include_revoked: true,
+        })
+        .await;
+    assert_eq!(endorsements, vec![EndorsementWithRevocation {
+      record: endorsement,
+      revocation: Some(revocation),
+    }]);
+    
+    // Once revoked, Bob can endorse the report again
+    create_endorsement(&conductors[1], &bob_zome, sample.clone()).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let endorsements: Vec<EndorsementWithRevocation> = conductors[0]
+        .call(&alice_zome, "get_endorsements_for_report", GetEndorsementsForReportInput {
+          report_hash: sample.report_hash.clone(),
+          include_revoked: false,
+        })
+        .await;
+    assert_eq!(endorsements.len(), 1);
+    assert!(endorsements[0].revocation.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn endorsement_stance_is_validated_and_counted() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(3, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,), (carol,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    let carol_zome = carol.zome("stewardship");
+    
+    // Bob and Carol both belong to the right holder
+    let mut right_holder = sample_actant_2(&conductors[1], &bob_zome).await;
+    right_holder.agents = vec![bobbo.agent_pubkey().clone(), carol.agent_pubkey().clone()];
+    right_holder.admins = vec![bobbo.agent_pubkey().clone()];
+    let right_holder_hash = create_actant(&conductors[1], &bob_zome, right_holder).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let invitations: Vec<Record> = conductors[2]
+        .call(&carol_zome, "get_my_invitations", ())
+        .await;
+    let _acceptance: Record = conductors[2]
+        .call(&carol_zome, "accept_invitation", invitations[0].signed_action.action_address().clone())
+        .await;
+    
+    let sample = sample_endorsement_1(&conductors[0], &alice_zome, right_holder_hash).await;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    // Confidence is bounded
+    let mut overconfident = sample.clone();
+    overconfident.confidence = MAX_CONFIDENCE + 1;
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_endorsement", overconfident)
+        .await;
+    assert!(result.is_err());
+    
+    // Disputes must say why
+    let mut dispute = sample.clone();
+    dispute.stance = EndorsementStance::Dispute;
+    dispute.comment = None;
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_endorsement", dispute.clone())
+        .await;
+    assert!(result.is_err());
+    
+    dispute.comment = Some("The site visit showed no progress".to_string());
+    create_endorsement(&conductors[1], &bob_zome, dispute).await;
+    create_endorsement(&conductors[2], &carol_zome, sample.clone()).await;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let counts: EndorsementStanceCounts = conductors[0]
+        .call(&alice_zome, "get_endorsement_stance_counts", sample.report_hash.clone())
+        .await;
+    assert_eq!(counts, EndorsementStanceCounts {
+      endorse: 1,
+      partially_endorse: 0,
+      dispute: 1,
+    });
+}
+
+/// The next `ReportVerified` signal, waiting for at most 10 seconds
+async fn next_report_verified(
+    signals: &mut (impl futures::Stream<Item = holochain::prelude::Signal> + Unpin),
+) -> Option<(ActionHash, ActionHash, Option<ActionHash>)> {
+    tokio::time::timeout(std::time::Duration::from_secs(10), async {
+        while let Some(signal) = signals.next().await {
+            if let holochain::prelude::Signal::App(_, app_signal) = signal {
+                if let Ok(stewardship::Signal::ReportVerified { report_hash, endorsement_hash, revocation_hash }) = app_signal
+                    .into_inner()
+                    .decode::<stewardship::Signal>()
+                {
+                    return Some((report_hash, endorsement_hash, revocation_hash));
+                }
+            }
+        }
+        None
+    })
+    .await
+    .unwrap_or(None)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn report_is_verified_once_quorum_of_right_holders_endorse() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(3, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,), (carol,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    let carol_zome = carol.zome("stewardship");
+    
+    // Bob and Carol each hold a right on the clause, which needs both of them to verify a report
+    let bob_holder_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    let carol_holder_hash = create_actant(&conductors[2], &carol_zome, sample_actant_2(&conductors[2], &carol_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let mut clause = sample_clause_1(&conductors[0], &alice_zome).await;
+    clause.right_holders = vec![bob_holder_hash.clone(), carol_holder_hash.clone()];
+    clause.endorsement_quorum = 2;
+    let report = sample_report_for_clause(&conductors[0], &alice_zome, clause).await;
+    let report_hash = create_report(&conductors[0], &alice_zome, report).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let endorsement = Endorsement {
+      report_hash: report_hash.clone(),
+      stance: EndorsementStance::Endorse,
+      comment: None,
+      confidence: MAX_CONFIDENCE,
+      actant_hash: None,
+      actant_revision_hashes: vec![],
+    };
+    
+    // Bob cannot endorse on behalf of Carol's Actant
+    let mut on_behalf_of_carol = endorsement.clone();
+    on_behalf_of_carol.actant_hash = Some(carol_holder_hash.clone());
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_endorsement", on_behalf_of_carol)
+        .await;
+    assert!(result.is_err());
+    
+    let bob_endorsement = create_endorsement(&conductors[1], &bob_zome, endorsement.clone()).await;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let status: ReportStatus = conductors[0]
+        .call(&alice_zome, "get_report_status", report_hash.clone())
+        .await;
+    assert_eq!(status.status, VerificationStatus::Pending);
+    assert_eq!(status.endorsing_actants, vec![bob_holder_hash.clone()]);
+    
+    // Carol's endorsement reaches the quorum, which her conductor signals to Alice too
+    let mut signals = Box::pin(conductors[2].signals());
+    let mut alice_signals = Box::pin(conductors[0].signals());
+    let carol_endorsement_hash = create_endorsement(&conductors[2], &carol_zome, endorsement.clone())
+        .await
+        .signed_action
+        .hashed
+        .hash;
+    let verified = next_report_verified(&mut signals).await;
+    assert_eq!(verified, Some((report_hash.clone(), carol_endorsement_hash.clone(), None)));
+    let verified = next_report_verified(&mut alice_signals).await;
+    assert_eq!(verified, Some((report_hash.clone(), carol_endorsement_hash, None)));
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let mut right_holders = vec![bob_holder_hash.clone(), carol_holder_hash.clone()];
+    right_holders.sort();
+    for (conductor, zome) in [(&conductors[0], &alice_zome), (&conductors[1], &bob_zome)] {
+        let status: ReportStatus = conductor
+            .call(zome, "get_report_status", report_hash.clone())
+            .await;
+        assert_eq!(status, ReportStatus {
+          status: VerificationStatus::Verified,
+          quorum: 2,
+          endorsing_actants: right_holders.clone(),
+          disputing_actants: vec![],
+        });
+    }
+    
+    // Bob changes his mind and disputes the report
+    let _revocation: Record = conductors[1]
+        .call(&bob_zome, "revoke_endorsement", RevokeEndorsementInput {
+          endorsement_hash: bob_endorsement.signed_action.hashed.hash.clone(),
+          reason: None,
+        })
+        .await;
+    let mut dispute = endorsement.clone();
+    dispute.stance = EndorsementStance::Dispute;
+    dispute.comment = Some("The figures do not add up".to_string());
+    create_endorsement(&conductors[1], &bob_zome, dispute).await;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let status: ReportStatus = conductors[2]
+        .call(&carol_zome, "get_report_status", report_hash.clone())
+        .await;
+    assert_eq!(status.status, VerificationStatus::Disputed);
+    assert_eq!(status.endorsing_actants, vec![carol_holder_hash]);
+    assert_eq!(status.disputing_actants, vec![bob_holder_hash]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn revoking_the_only_dispute_verifies_the_report() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(3, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,), (carol,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    let carol_zome = carol.zome("stewardship");
+    
+    // Bob and Carol each hold a right on the clause, which needs one endorsement
+    let bob_holder_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    let carol_holder_hash = create_actant(&conductors[2], &carol_zome, sample_actant_2(&conductors[2], &carol_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let mut clause = sample_clause_1(&conductors[0], &alice_zome).await;
+    clause.right_holders = vec![bob_holder_hash, carol_holder_hash];
+    clause.endorsement_quorum = 1;
+    let report = sample_report_for_clause(&conductors[0], &alice_zome, clause).await;
+    let report_hash = create_report(&conductors[0], &alice_zome, report).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    // Carol disputes the report, so Bob's endorsement does not verify it
+    let endorsement = Endorsement {
+      report_hash: report_hash.clone(),
+      stance: EndorsementStance::Endorse,
+      comment: None,
+      confidence: MAX_CONFIDENCE,
+      actant_hash: None,
+      actant_revision_hashes: vec![],
+    };
+    let mut dispute = endorsement.clone();
+    dispute.stance = EndorsementStance::Dispute;
+    let dispute_hash = create_endorsement(&conductors[2], &carol_zome, dispute).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    create_endorsement(&conductors[1], &bob_zome, endorsement).await;
+    
+    consistency_10s([&alice, &bobbo, &carol]).await;
+    
+    let status: ReportStatus = conductors[0]
+        .call(&alice_zome, "get_report_status", report_hash.clone())
+        .await;
+    assert_eq!(status.status, VerificationStatus::Disputed);
+    
+    // Carol withdraws her dispute, which verifies the report
+    let mut signals = Box::pin(conductors[0].signals());
+    let revocation: Record = conductors[2]
+        .call(&carol_zome, "revoke_endorsement", RevokeEndorsementInput {
+          endorsement_hash: dispute_hash.clone(),
+          reason: None,
+        })
+        .await;
+    let verified = next_report_verified(&mut signals).await;
+    assert_eq!(
+        verified,
+        Some((report_hash, dispute_hash, Some(revocation.signed_action.hashed.hash))),
+    );
+}
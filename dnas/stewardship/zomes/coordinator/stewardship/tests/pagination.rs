@@ -0,0 +1,115 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use std::collections::BTreeMap;
+
+use hdk::prelude::*;
+
+use stewardship_integrity::*;
+
+use stewardship::pagination::{select_page, PageCursor, PageInput, SortDirection};
+use stewardship::time_shard::walk_shards_in_order;
+
+const DAY: i64 = 86_400_000_000;
+const HOUR: i64 = 3_600_000_000;
+/// 2023-12-30T00:00:00Z, so that the entries span a month and a year boundary
+const START: i64 = 1_703_894_400_000_000;
+
+/// Day shards holding cursors, each filed under the day its record was created
+fn sample_shards() -> BTreeMap<[u32; 3], Vec<PageCursor>> {
+    let entries = [
+        (START + HOUR, START + HOUR),
+        (START + 2 * HOUR, START + 2 * HOUR),
+        (START + DAY + HOUR, START + DAY + HOUR),
+        // Created just before midnight and linked just after it
+        (START + 2 * DAY - 1, START + 2 * DAY + 1),
+        (START + 2 * DAY + HOUR, START + 2 * DAY + HOUR),
+        (START + 3 * DAY + HOUR, START + 3 * DAY + HOUR),
+        (START + 6 * DAY + HOUR, START + 6 * DAY + HOUR),
+        (START + 6 * DAY + 2 * HOUR, START + 6 * DAY + 2 * HOUR),
+    ];
+    let mut shards: BTreeMap<[u32; 3], Vec<PageCursor>> = BTreeMap::new();
+    for (index, (created_at, linked_at)) in entries.into_iter().enumerate() {
+        shards
+            .entry(shard_date(Timestamp::from_micros(created_at)))
+            .or_default()
+            .push(PageCursor {
+              timestamp: Timestamp::from_micros(linked_at),
+              action_hash: ActionHash::from_raw_36(vec![index as u8; 36]),
+            });
+    }
+    shards
+}
+
+/// Gets one page from the shards, along with the number of day shards visited
+fn get_page(
+    shards: &BTreeMap<[u32; 3], Vec<PageCursor>>,
+    input: PageInput,
+) -> (Vec<PageCursor>, Option<PageCursor>, usize) {
+    let mut days_visited = 0;
+    let cursors = walk_shards_in_order(
+        |date_prefix| {
+            let mut values: Vec<u32> = shards
+                .keys()
+                .filter(|date| date[..date_prefix.len()] == *date_prefix)
+                .map(|date| date[date_prefix.len()])
+                .collect();
+            values.dedup();
+            Ok(values)
+        },
+        |date| {
+            days_visited += 1;
+            Ok(shards.get(date).cloned().unwrap_or_default())
+        },
+        input.after.as_ref(),
+        input.direction,
+        input.page_size + 1,
+    )
+    .unwrap();
+    let (cursors, next_cursor) = select_page(cursors, &input);
+    (cursors, next_cursor, days_visited)
+}
+
+/// Every page in turn, along with the day shards visited for each
+fn get_all_pages(
+    shards: &BTreeMap<[u32; 3], Vec<PageCursor>>,
+    direction: SortDirection,
+) -> (Vec<PageCursor>, Vec<usize>) {
+    let mut cursors = Vec::new();
+    let mut days_visited = Vec::new();
+    let mut after = None;
+    loop {
+        let (page, next_cursor, visited) = get_page(shards, PageInput {
+          page_size: 2,
+          after,
+          direction,
+        });
+        cursors.extend(page);
+        days_visited.push(visited);
+        match next_cursor {
+            Some(next_cursor) => after = Some(next_cursor),
+            None => return (cursors, days_visited),
+        }
+    }
+}
+
+#[test]
+fn pages_span_several_shards_in_order() {
+    let shards = sample_shards();
+    let mut all: Vec<PageCursor> = shards.values().flatten().cloned().collect();
+    all.sort();
+    assert_eq!(shards.len(), 5);
+
+    let (ascending, days_visited) = get_all_pages(&shards, SortDirection::Ascending);
+    assert_eq!(ascending, all);
+    assert_eq!(days_visited.len(), 4);
+    // The first page stops after the two earliest days
+    assert_eq!(days_visited[0], 2);
+
+    all.reverse();
+    let (descending, days_visited) = get_all_pages(&shards, SortDirection::Descending);
+    assert_eq!(descending, all);
+    // Entries of a day are only settled once the day before it has been visited
+    assert_eq!(days_visited[0], 3);
+}
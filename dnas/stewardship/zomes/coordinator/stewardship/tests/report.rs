@@ -4,16 +4,25 @@
 
 use hdk::prelude::*;
 use holochain::test_utils::consistency_10s;
+use holochain::conductor::api::error::ConductorApiResult;
 use holochain::{conductor::config::ConductorConfig, sweettest::*};
 
 use stewardship_integrity::*;
 
+use stewardship::actant::UpdateActantInput;
+use stewardship::clause_transition::TransitionClauseInput;
+use stewardship::compliance::{ClauseCompliance, ComplianceStatus};
+use stewardship::report::{
+    AddReportForClauseInput, CorrectReportInput, EffectiveReport, GetReportsForClauseByTypeInput,
+    RetractReportInput,
+};
+
 
 mod common;
-use common::{create_report, sample_report_1, sample_report_2};
+use common::{create_report, sample_report_1, sample_report_2, sample_report_for_clause};
 
 use common::{create_actant, sample_actant_1, sample_actant_2};
-use common::{create_clause, sample_clause_1, sample_clause_2};
+use common::{activate_clause, create_clause, sample_clause_1, sample_clause_2};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn create_report_test() {
@@ -66,11 +75,666 @@ async fn create_and_read_report() {
     
     consistency_10s([&alice, &bobbo]).await;
     
-    let get_record: Option<Record> = conductors[1]
+    let get_report: Option<EffectiveReport> = conductors[1]
         .call(&bob_zome, "get_report", record.signed_action.action_address().clone())
         .await;
+    let get_report = get_report.unwrap();
         
-    assert_eq!(record, get_record.unwrap());    
+    assert_eq!(record, get_report.record);
+    assert_eq!(sample, get_report.report);
+    assert!(!get_report.retracted);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn non_member_cannot_create_report() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // The sample Actant and Clause are created by Alice
+    let sample = sample_report_1(&conductors[0], &alice_zome).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob is not an agent of the reporting Actant
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_report", sample.clone())
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn guardian_can_report_for_more_than_human_actant() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Bob's organisation is the guardian of a river that Alice registers
+    let mut guardian = sample_actant_2(&conductors[1], &bob_zome).await;
+    guardian.kind = ActantKind::Organisation;
+    let guardian_hash = create_actant(&conductors[1], &bob_zome, guardian).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let mut river = sample_actant_1(&conductors[0], &alice_zome).await;
+    river.kind = ActantKind::MoreThanHuman;
+    river.guardians = vec![guardian_hash.clone()];
+    let river_hash = create_actant(&conductors[0], &alice_zome, river).await.signed_action.hashed.hash;
+    let mut clause = sample_clause_1(&conductors[0], &alice_zome).await;
+    clause.responsibilty_holders = vec![river_hash.clone()];
+    let clause_hash = create_clause(&conductors[0], &alice_zome, clause).await.signed_action.hashed.hash;
+    let transition_hash = activate_clause(&conductors[0], &alice_zome, clause_hash.clone()).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob reports for the river through its guardian
+    let sample = Report {
+      report_type: ReportType::Incident,
+      content: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
+      actant_hash: river_hash.clone(),
+      clause_hash,
+      actant_revision_hash: Some(river_hash),
+      clause_transition_hash: Some(transition_hash),
+      guardian_revision_hashes: vec![guardian_hash],
+    };
+    let record: Record = create_report(&conductors[1], &bob_zome, sample.clone()).await;
+    let entry: Report = record.entry().to_app_option().unwrap().unwrap();
+    assert!(entry.eq(&sample));
+    
+    // Bob can also correct the reports Alice files for the river
+    let alice_report_hash = create_report(&conductors[0], &alice_zome, sample.clone()).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let corrected: EffectiveReport = conductors[1]
+        .call(&bob_zome, "correct_report", CorrectReportInput {
+          report_hash: alice_report_hash,
+          content: "Corrected by the guardian".to_string(),
+        })
+        .await;
+    assert_eq!(corrected.report.content, "Corrected by the guardian");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cannot_report_as_actant_revision_older_than_one_already_cited() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Alice creates an Actant with Bob as an agent, and Bob accepts the invitation
+    let mut actant = sample_actant_1(&conductors[0], &alice_zome).await;
+    actant.agents.push(bob_zome.cell_id().agent_pubkey().clone());
+    let actant_hash = create_actant(&conductors[0], &alice_zome, actant.clone()).await.signed_action.hashed.hash;
+    let mut clause = sample_clause_1(&conductors[0], &alice_zome).await;
+    clause.responsibilty_holders = vec![actant_hash.clone()];
+    let clause_hash = create_clause(&conductors[0], &alice_zome, clause).await.signed_action.hashed.hash;
+    let transition_hash = activate_clause(&conductors[0], &alice_zome, clause_hash.clone()).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let invitations: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_my_invitations", ())
+        .await;
+    let _acceptance: Record = conductors[1]
+        .call(&bob_zome, "accept_invitation", invitations[0].signed_action.hashed.hash.clone())
+        .await;
+    
+    // Alice renames the Actant, keeping Bob
+    actant.name = "Renamed".to_string();
+    let revision: Record = conductors[0]
+        .call(&alice_zome, "update_actant", UpdateActantInput {
+          original_actant_hash: actant_hash.clone(),
+          previous_actant_hash: actant_hash.clone(),
+          updated_actant: actant,
+        })
+        .await;
+    let revision_hash = revision.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob reports as the new revision
+    let sample = Report {
+      report_type: ReportType::Incident,
+      content: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
+      actant_hash: actant_hash.clone(),
+      clause_hash,
+      actant_revision_hash: Some(revision_hash),
+      clause_transition_hash: Some(transition_hash),
+      guardian_revision_hashes: vec![],
+    };
+    create_report(&conductors[1], &bob_zome, sample.clone()).await;
+    
+    // Having cited it, Bob can no longer report as the original revision
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_report", Report {
+          actant_revision_hash: Some(actant_hash),
+          ..sample
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cannot_report_against_inactive_clause() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (_bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    
+    let clause = sample_clause_1(&conductors[0], &alice_zome).await;
+    let actant_hash = clause.responsibilty_holders[0].clone();
+    let clause_hash = create_clause(&conductors[0], &alice_zome, clause).await.signed_action.hashed.hash;
+    let sample = Report {
+      report_type: ReportType::Progress,
+      content: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
+      actant_hash: actant_hash.clone(),
+      clause_hash: clause_hash.clone(),
+      actant_revision_hash: Some(actant_hash),
+      clause_transition_hash: None,
+      guardian_revision_hashes: vec![],
+    };
+    
+    // The clause is still a draft
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_report", sample.clone())
+        .await;
+    assert!(result.is_err());
+    
+    // Once suspended, the clause no longer accepts reports either
+    activate_clause(&conductors[0], &alice_zome, clause_hash.clone()).await;
+    let _suspended: Record = conductors[0]
+        .call(&alice_zome, "transition_clause", TransitionClauseInput {
+          clause_hash: clause_hash.clone(),
+          to: ClauseStatus::Suspended,
+        })
+        .await;
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_report", sample)
+        .await;
+    assert!(result.is_err());
+    
+    // Terminated clauses cannot be reactivated
+    let _terminated: Record = conductors[0]
+        .call(&alice_zome, "transition_clause", TransitionClauseInput {
+          clause_hash: clause_hash.clone(),
+          to: ClauseStatus::Terminated,
+        })
+        .await;
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "transition_clause", TransitionClauseInput {
+          clause_hash,
+          to: ClauseStatus::Active,
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cannot_report_against_clause_suspended_by_another_holder() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Bob's Actant holds the rights, Alice's the responsibilities
+    let alice_actant_hash = create_actant(&conductors[0], &alice_zome, sample_actant_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+    let bob_actant_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let mut clause = sample_clause_1(&conductors[0], &alice_zome).await;
+    clause.right_holders = vec![bob_actant_hash];
+    clause.responsibilty_holders = vec![alice_actant_hash.clone()];
+    let clause_hash = create_clause(&conductors[0], &alice_zome, clause).await.signed_action.hashed.hash;
+    let transition_hash = activate_clause(&conductors[0], &alice_zome, clause_hash.clone()).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob suspends the clause Alice activated
+    let _suspended: Record = conductors[1]
+        .call(&bob_zome, "transition_clause", TransitionClauseInput {
+          clause_hash: clause_hash.clone(),
+          to: ClauseStatus::Suspended,
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Alice can no longer report against the transition that activated it
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_report", Report {
+          report_type: ReportType::Progress,
+          content: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
+          actant_hash: alice_actant_hash.clone(),
+          clause_hash,
+          actant_revision_hash: Some(alice_actant_hash),
+          clause_transition_hash: Some(transition_hash),
+          guardian_revision_hashes: vec![],
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_reports_for_clause_by_type() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Alice files a progress report and a breach report on the same clause
+    let progress = sample_report_1(&conductors[0], &alice_zome).await;
+    let progress_record: Record = create_report(&conductors[0], &alice_zome, progress.clone()).await;
+    let mut breach = progress.clone();
+    breach.report_type = ReportType::Breach;
+    let breach_record: Record = create_report(&conductors[0], &alice_zome, breach).await;
+    
+    // Custom types must be listed in the DNA properties, which this network leaves empty
+    let mut custom = progress.clone();
+    custom.report_type = ReportType::Custom("Water quality".to_string());
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_report", custom)
+        .await;
+    assert!(result.is_err());
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let breaches: Vec<EffectiveReport> = conductors[1]
+        .call(&bob_zome, "get_reports_for_clause_by_type", GetReportsForClauseByTypeInput {
+          clause_hash: progress.clause_hash.clone(),
+          report_type: ReportType::Breach,
+        })
+        .await;
+    let breaches: Vec<Record> = breaches.into_iter().map(|r| r.record).collect();
+    assert_eq!(breaches, vec![breach_record]);
+    
+    let progress_reports: Vec<EffectiveReport> = conductors[1]
+        .call(&bob_zome, "get_reports_for_clause_by_type", GetReportsForClauseByTypeInput {
+          clause_hash: progress.clause_hash.clone(),
+          report_type: ReportType::Progress,
+        })
+        .await;
+    let progress_reports: Vec<Record> = progress_reports.into_iter().map(|r| r.record).collect();
+    assert_eq!(progress_reports, vec![progress_record]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn clause_cannot_link_to_another_clauses_report_or_under_the_wrong_type() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (_bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    
+    // Alice reports on two different clauses
+    let report_1 = sample_report_1(&conductors[0], &alice_zome).await;
+    let report_1_hash = create_report(&conductors[0], &alice_zome, report_1.clone()).await.signed_action.hashed.hash;
+    let report_2 = sample_report_2(&conductors[0], &alice_zome).await;
+    let report_2_hash = create_report(&conductors[0], &alice_zome, report_2.clone()).await.signed_action.hashed.hash;
+    
+    // The first clause cannot list the report on the second one
+    let result: ConductorApiResult<()> = conductors[0]
+        .call_fallible(&alice_zome, "add_report_for_clause", AddReportForClauseInput {
+          base_clause_hash: report_1.clause_hash.clone(),
+          target_report_hash: report_2_hash,
+          report_type: ReportType::Progress,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Nor list its own report under a type the report does not have
+    let result: ConductorApiResult<()> = conductors[0]
+        .call_fallible(&alice_zome, "add_report_for_clause", AddReportForClauseInput {
+          base_clause_hash: report_1.clause_hash.clone(),
+          target_report_hash: report_1_hash.clone(),
+          report_type: ReportType::Breach,
+        })
+        .await;
+    assert!(result.is_err());
+    
+    // Under its own type it can
+    let result: ConductorApiResult<()> = conductors[0]
+        .call_fallible(&alice_zome, "add_report_for_clause", AddReportForClauseInput {
+          base_clause_hash: report_1.clause_hash.clone(),
+          target_report_hash: report_1_hash,
+          report_type: ReportType::Progress,
+        })
+        .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn custom_report_type_listed_in_dna_properties_is_accepted() {
+    // Use prebuilt dna file, listing a custom report type in its properties
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+    let properties: SerializedBytes = DnaProperties {
+      custom_report_types: vec!["Water quality".to_string()],
+    }
+    .try_into()
+    .unwrap();
+    let dna = dna.update_modifiers(DnaModifiersOpt::none().with_properties(properties));
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let report_types: Vec<ReportType> = conductors[1]
+        .call(&bob_zome, "get_report_types", ())
+        .await;
+    assert_eq!(report_types.last(), Some(&ReportType::Custom("Water quality".to_string())));
+    
+    let mut custom = sample_report_1(&conductors[0], &alice_zome).await;
+    custom.report_type = ReportType::Custom("Water quality".to_string());
+    let record: Record = create_report(&conductors[0], &alice_zome, custom.clone()).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let reports: Vec<EffectiveReport> = conductors[1]
+        .call(&bob_zome, "get_reports_for_clause_by_type", GetReportsForClauseByTypeInput {
+          clause_hash: custom.clause_hash.clone(),
+          report_type: custom.report_type.clone(),
+        })
+        .await;
+    let reports: Vec<Record> = reports.into_iter().map(|r| r.record).collect();
+    assert_eq!(reports, vec![record]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn corrected_and_retracted_report_keeps_its_original() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    let sample = sample_report_1(&conductors[0], &alice_zome).await;
+    let record: Record = create_report(&conductors[0], &alice_zome, sample.clone()).await;
+    let report_hash = record.signed_action.action_address().clone();
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Bob is not a member of Alice's Actant
+    let result: ConductorApiResult<EffectiveReport> = conductors[1]
+        .call_fallible(&bob_zome, "correct_report", CorrectReportInput {
+          report_hash: report_hash.clone(),
+          content: "Not my report".to_string(),
+        })
+        .await;
+    assert!(result.is_err());
+    let result: ConductorApiResult<EffectiveReport> = conductors[1]
+        .call_fallible(&bob_zome, "retract_report", RetractReportInput {
+          report_hash: report_hash.clone(),
+          reason: "Not my report".to_string(),
+        })
+        .await;
+    assert!(result.is_err());
+    
+    let corrected: EffectiveReport = conductors[0]
+        .call(&alice_zome, "correct_report", CorrectReportInput {
+          report_hash: report_hash.clone(),
+          content: "Corrected content".to_string(),
+        })
+        .await;
+    assert_eq!(corrected.report.content, "Corrected content");
+    assert!(corrected.correction_hash.is_some());
+    
+    // A further correction follows the current one, whatever its timestamp
+    let recorrected: EffectiveReport = conductors[0]
+        .call(&alice_zome, "correct_report", CorrectReportInput {
+          report_hash: report_hash.clone(),
+          content: "Corrected again".to_string(),
+        })
+        .await;
+    assert_eq!(recorrected.report.content, "Corrected again");
+    assert_ne!(recorrected.correction_hash, corrected.correction_hash);
+    
+    let _retracted: EffectiveReport = conductors[0]
+        .call(&alice_zome, "retract_report", RetractReportInput {
+          report_hash: report_hash.clone(),
+          reason: "Filed against the wrong clause".to_string(),
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let reports: Vec<EffectiveReport> = conductors[1]
+        .call(&bob_zome, "get_reports_for_clause", sample.clause_hash.clone())
+        .await;
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].record, record);
+    assert_eq!(reports[0].report.content, "Corrected again");
+    assert!(reports[0].retracted);
+    assert!(reports[0].retraction_hash.is_some());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn compliance_summary_counts_verified_reports_per_clause() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Alice's Actant is responsible for three clauses where Bob's Actant holds the right
+    let right_holder_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    let responsible_hash = create_actant(&conductors[0], &alice_zome, sample_actant_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let mut clause = sample_clause_1(&conductors[0], &alice_zome).await;
+    clause.right_holders = vec![right_holder_hash];
+    clause.responsibilty_holders = vec![responsible_hash.clone()];
+    
+    let verified = sample_report_for_clause(&conductors[0], &alice_zome, clause.clone()).await;
+    let verified_record = create_report(&conductors[0], &alice_zome, verified.clone()).await;
+    
+    let mut pending_clause = clause.clone();
+    pending_clause.title = "Pending".to_string();
+    let pending = sample_report_for_clause(&conductors[0], &alice_zome, pending_clause).await;
+    create_report(&conductors[0], &alice_zome, pending.clone()).await;
+    
+    let mut unreported_clause = clause.clone();
+    unreported_clause.title = "Unreported".to_string();
+    let unreported_hash = create_clause(&conductors[0], &alice_zome, unreported_clause).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let _endorsement: Record = conductors[1]
+        .call(&bob_zome, "create_endorsement", Endorsement {
+          report_hash: verified_record.signed_action.hashed.hash.clone(),
+          stance: EndorsementStance::Endorse,
+          comment: None,
+          confidence: MAX_CONFIDENCE,
+          actant_hash: None,
+          actant_revision_hashes: vec![],
+        })
+        .await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let summary: Vec<ClauseCompliance> = conductors[1]
+        .call(&bob_zome, "get_compliance_summary", responsible_hash)
+        .await;
+    assert_eq!(summary.len(), 3);
+    
+    let for_clause = |clause_hash: &ActionHash| {
+        summary.iter().find(|c| &c.clause_hash == clause_hash).unwrap().clone()
+    };
+    let verified_summary = for_clause(&verified.clause_hash);
+    assert_eq!(verified_summary.report_count, 1);
+    assert_eq!(verified_summary.verified_report_count, 1);
+    assert_eq!(verified_summary.latest_report_at, Some(verified_record.action().timestamp()));
+    assert_eq!(verified_summary.status, ComplianceStatus::Compliant);
+    
+    let pending_summary = for_clause(&pending.clause_hash);
+    assert_eq!(pending_summary.report_count, 1);
+    assert_eq!(pending_summary.verified_report_count, 0);
+    assert_eq!(pending_summary.status, ComplianceStatus::Pending);
+    
+    let unreported_summary = for_clause(&unreported_hash);
+    assert_eq!(unreported_summary.report_count, 0);
+    assert_eq!(unreported_summary.latest_report_at, None);
+    assert_eq!(unreported_summary.status, ComplianceStatus::Unreported);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn compliance_summary_only_counts_the_actant_reports() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/stewardship.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("stewardship", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
 
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("stewardship");
+    let bob_zome = bobbo.zome("stewardship");
+    
+    // Alice's and Bob's Actants are both responsible for the same clause
+    let alice_actant_hash = create_actant(&conductors[0], &alice_zome, sample_actant_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+    let bob_actant_hash = create_actant(&conductors[1], &bob_zome, sample_actant_2(&conductors[1], &bob_zome).await).await.signed_action.hashed.hash;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let mut clause = sample_clause_1(&conductors[0], &alice_zome).await;
+    clause.right_holders = vec![alice_actant_hash.clone()];
+    clause.responsibilty_holders = vec![alice_actant_hash.clone(), bob_actant_hash.clone()];
+    let alice_report = sample_report_for_clause(&conductors[0], &alice_zome, clause).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    // Only Bob's Actant reports
+    let bob_report = Report {
+      actant_hash: bob_actant_hash.clone(),
+      actant_revision_hash: Some(bob_actant_hash.clone()),
+      ..alice_report.clone()
+    };
+    create_report(&conductors[1], &bob_zome, bob_report).await;
+    
+    consistency_10s([&alice, &bobbo]).await;
+    
+    let summary: Vec<ClauseCompliance> = conductors[0]
+        .call(&alice_zome, "get_compliance_summary", alice_actant_hash)
+        .await;
+    assert_eq!(summary.len(), 1);
+    assert_eq!(summary[0].report_count, 0);
+    assert_eq!(summary[0].status, ComplianceStatus::Unreported);
+    
+    let summary: Vec<ClauseCompliance> = conductors[0]
+        .call(&alice_zome, "get_compliance_summary", bob_actant_hash)
+        .await;
+    assert_eq!(summary.len(), 1);
+    assert_eq!(summary[0].report_count, 1);
+    assert_eq!(summary[0].status, ComplianceStatus::Pending);
+}
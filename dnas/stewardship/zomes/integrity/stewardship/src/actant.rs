@@ -3,37 +3,374 @@ use hdi::prelude::*;
 #[derive(Clone, PartialEq)]
 pub struct Actant {
     pub agents: Vec<AgentPubKey>,
+    /// Agents allowed to change the membership and roles; always a subset of `agents`
+    pub admins: Vec<AgentPubKey>,
     pub name: String,
+    pub kind: ActantKind,
+    /// Actants authorised to report and endorse on behalf of a more-than-human Actant
+    pub guardians: Vec<ActionHash>,
+    /// Revisions of the guardians their kind is checked against, the original of any
+    /// guardian left out
+    #[serde(default)]
+    pub guardian_revision_hashes: Vec<ActionHash>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ActantKind {
+    Human,
+    Organisation,
+    Collective,
+    MoreThanHuman,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ActantRole {
+    Admin,
+    Member,
+}
+impl Actant {
+    pub fn role_of(&self, agent: &AgentPubKey) -> Option<ActantRole> {
+        if self.admins.contains(agent) {
+            Some(ActantRole::Admin)
+        } else if self.agents.contains(agent) {
+            Some(ActantRole::Member)
+        } else {
+            None
+        }
+    }
+}
+fn same_agents(a: &[AgentPubKey], b: &[AgentPubKey]) -> bool {
+    a.iter().all(|agent| b.contains(agent)) && b.iter().all(|agent| a.contains(agent))
+}
+fn validate_roles(actant: &Actant) -> Option<ValidateCallbackResult> {
+    if actant.admins.is_empty() {
+        return Some(
+            ValidateCallbackResult::Invalid(
+                String::from("An Actant must have at least one admin"),
+            ),
+        );
+    }
+    if actant.admins.iter().any(|admin| !actant.agents.contains(admin)) {
+        return Some(
+            ValidateCallbackResult::Invalid(
+                String::from("The admins of an Actant must be among its agents"),
+            ),
+        );
+    }
+    None
+}
+/// Fetches the Actant at `revision_hash`, checking that it is `original_actant_hash`
+/// itself or one of its updates. Returns `None` if it is not a revision of that Actant.
+pub fn must_get_actant_revision(
+    original_actant_hash: &ActionHash,
+    revision_hash: &ActionHash,
+) -> ExternResult<Option<Actant>> {
+    let record = must_get_valid_record(revision_hash.clone())?;
+    let actant: crate::Actant = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if !crate::is_revision_of(original_actant_hash, record)? {
+        return Ok(None);
+    }
+    Ok(Some(actant))
+}
+/// Whether `agent` counts as a member of the given revision of an Actant: it must be listed
+/// in its agents and, on its source chain up to `chain_top`, have accepted an invitation
+/// to one of the revisions listing it since it was last added, unless it created the
+/// Actant and has been listed ever since
+pub fn is_actant_member(
+    original_actant_hash: &ActionHash,
+    revision_hash: &ActionHash,
+    actant: &Actant,
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    if !actant.agents.contains(agent) {
+        return Ok(false);
+    }
+    let mut membership_revision_hashes: Vec<ActionHash> = Vec::new();
+    let mut current = must_get_valid_record(revision_hash.clone())?;
+    loop {
+        let listed_actant: Option<crate::Actant> = current
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        if !listed_actant.map_or(false, |listed_actant| listed_actant.agents.contains(agent)) {
+            break;
+        }
+        membership_revision_hashes.push(current.action_address().clone());
+        match current.action() {
+            Action::Update(update) => {
+                current = must_get_valid_record(update.original_action_address.clone())?;
+            }
+            _ => {
+                if current.action().author() == agent {
+                    return Ok(true);
+                }
+                break;
+            }
+        }
+    }
+    crate::has_accepted_invitation(
+        agent,
+        chain_top,
+        original_actant_hash,
+        &membership_revision_hashes,
+    )
+}
+/// The revision of the Actant cited among `revision_hashes`, or its original revision if
+/// none is, along with its hash
+fn must_get_cited_actant_revision(
+    actant_hash: &ActionHash,
+    revision_hashes: &[ActionHash],
+) -> ExternResult<(ActionHash, Actant)> {
+    for revision_hash in revision_hashes {
+        if let Some(actant) = must_get_actant_revision(actant_hash, revision_hash)? {
+            return Ok((revision_hash.clone(), actant));
+        }
+    }
+    let record = must_get_valid_record(actant_hash.clone())?;
+    let actant: crate::Actant = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    Ok((actant_hash.clone(), actant))
+}
+/// Whether `agent` is a member of any of the given Actants, as of the revisions cited in
+/// `revision_hashes`. Actants without a cited revision are evaluated as of their original
+/// revision, and revisions the agent has since updated are not accepted
+pub fn is_member_of_any_actant(
+    actant_hashes: &[ActionHash],
+    revision_hashes: &[ActionHash],
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    for actant_hash in actant_hashes {
+        let (revision_hash, actant) = must_get_cited_actant_revision(actant_hash, revision_hashes)?;
+        if is_actant_member(actant_hash, &revision_hash, &actant, agent, chain_top)?
+            && !is_revision_superseded_by(&revision_hash, agent, chain_top)?
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+/// Whether `agent` can act for the given revision of an Actant, either as one of its
+/// members or as a member of one of its guardians, as of the revisions cited in
+/// `revision_hashes`
+pub fn is_authorised_for_actant(
+    original_actant_hash: &ActionHash,
+    revision_hash: &ActionHash,
+    actant: &Actant,
+    revision_hashes: &[ActionHash],
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    if is_actant_member(original_actant_hash, revision_hash, actant, agent, chain_top)? {
+        return Ok(true);
+    }
+    is_member_of_any_actant(&actant.guardians, revision_hashes, agent, chain_top)
+}
+/// Whether `agent` can act for any of the given Actants, as of the revisions cited in
+/// `revision_hashes` like `is_member_of_any_actant`
+pub fn is_authorised_for_any_actant(
+    actant_hashes: &[ActionHash],
+    revision_hashes: &[ActionHash],
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    for actant_hash in actant_hashes {
+        let (revision_hash, actant) = must_get_cited_actant_revision(actant_hash, revision_hashes)?;
+        if is_revision_superseded_by(&revision_hash, agent, chain_top)? {
+            continue;
+        }
+        if is_authorised_for_actant(
+            actant_hash,
+            &revision_hash,
+            &actant,
+            revision_hashes,
+            agent,
+            chain_top,
+        )? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+/// Checks the guardians as of the revisions the Actant cites, which must be the latest ones
+/// on the source chain of `author` up to `chain_top`
+fn validate_guardians(
+    actant: &Actant,
+    author: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<Option<ValidateCallbackResult>> {
+    if actant.guardians.is_empty() {
+        return Ok(None);
+    }
+    if actant.kind != ActantKind::MoreThanHuman {
+        return Ok(
+            Some(
+                ValidateCallbackResult::Invalid(
+                    String::from("Only more-than-human Actants can have guardians"),
+                ),
+            ),
+        );
+    }
+    for guardian_hash in actant.guardians.iter() {
+        let (revision_hash, guardian) = must_get_cited_actant_revision(
+            guardian_hash,
+            &actant.guardian_revision_hashes,
+        )?;
+        if is_revision_superseded_by(&revision_hash, author, chain_top)? {
+            return Ok(
+                Some(
+                    ValidateCallbackResult::Invalid(
+                        String::from("Guardians must be cited as of their latest revision"),
+                    ),
+                ),
+            );
+        }
+        if guardian.kind == ActantKind::MoreThanHuman {
+            return Ok(
+                Some(
+                    ValidateCallbackResult::Invalid(
+                        String::from("A more-than-human Actant cannot be a guardian"),
+                    ),
+                ),
+            );
+        }
+    }
+    Ok(None)
 }
 pub fn validate_create_actant(
-    _action: EntryCreationAction,
-    _actant: Actant,
+    action: EntryCreationAction,
+    actant: Actant,
 ) -> ExternResult<ValidateCallbackResult> {
+    if let Some(invalid) = validate_roles(&actant) {
+        return Ok(invalid);
+    }
+    if let Some(invalid) = validate_guardians(&actant, action.author(), action.prev_action())? {
+        return Ok(invalid);
+    }
+    if !actant.admins.contains(action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The creator of an Actant must be one of its admins"),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_update_actant(
-    _action: Update,
-    _actant: Actant,
+    action: Update,
+    actant: Actant,
     _original_action: EntryCreationAction,
-    _original_actant: Actant,
+    original_actant: Actant,
 ) -> ExternResult<ValidateCallbackResult> {
+    let original_actant_hash = crate::must_get_original_action_hash(
+        action.original_action_address.clone(),
+    )?;
+    if !is_actant_member(
+        &original_actant_hash,
+        &action.original_action_address,
+        &original_actant,
+        &action.author,
+        &action.prev_action,
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only members of an Actant can update it"),
+            ),
+        );
+    }
+    if let Some(invalid) = validate_roles(&actant) {
+        return Ok(invalid);
+    }
+    if let Some(invalid) = validate_guardians(&actant, &action.author, &action.prev_action)? {
+        return Ok(invalid);
+    }
+    let changes_roles = !same_agents(&actant.agents, &original_actant.agents)
+        || !same_agents(&actant.admins, &original_actant.admins)
+        || actant.kind != original_actant.kind
+        || actant.guardians != original_actant.guardians;
+    if changes_roles && !original_actant.admins.contains(&action.author)
+        && !is_leaving(&action.author, &original_actant, &actant)
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only admins of an Actant can change its membership, roles, kind or guardians"),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
+/// Whether the only membership change between the revisions is `agent` removing itself
+fn is_leaving(agent: &AgentPubKey, previous: &Actant, current: &Actant) -> bool {
+    let without = |agents: &[AgentPubKey]| -> Vec<AgentPubKey> {
+        agents.iter().filter(|a| *a != agent).cloned().collect()
+    };
+    !current.agents.contains(agent)
+        && same_agents(&current.agents, &without(&previous.agents))
+        && same_agents(&current.admins, &without(&previous.admins))
+}
 pub fn validate_delete_actant(
-    _action: Delete,
+    action: Delete,
     _original_action: EntryCreationAction,
-    _original_actant: Actant,
+    original_actant: Actant,
 ) -> ExternResult<ValidateCallbackResult> {
+    let original_actant_hash = crate::must_get_original_action_hash(
+        action.deletes_address.clone(),
+    )?;
+    if !original_actant.admins.contains(&action.author)
+        || !is_actant_member(
+            &original_actant_hash,
+            &action.deletes_address,
+            &original_actant,
+            &action.author,
+            &action.prev_action,
+        )?
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only admins of an Actant can delete it"),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
+/// Tag of the `ActantUpdates` link of a revision that merges concurrent updates,
+/// listing the other branch heads it resolves. Plain updates use an empty tag.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+pub struct ActantUpdateTag {
+    pub merged_revision_hashes: Vec<ActionHash>,
+}
+impl ActantUpdateTag {
+    pub fn from_tag(tag: LinkTag) -> ExternResult<Option<ActantUpdateTag>> {
+        if tag.0.is_empty() {
+            return Ok(None);
+        }
+        let tag = ActantUpdateTag::try_from(SerializedBytes::from(UnsafeBytes::from(tag.0)))
+            .map_err(|e| wasm_error!(e))?;
+        Ok(Some(tag))
+    }
+}
 pub fn validate_create_link_actant_updates(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
-    _tag: LinkTag,
+    tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::from(base_address);
-    let record = must_get_valid_record(action_hash)?;
+    let original_actant_hash = ActionHash::from(base_address);
+    let record = must_get_valid_record(original_actant_hash.clone())?;
     let _actant: crate::Actant = record
         .entry()
         .to_app_option()
@@ -54,6 +391,63 @@ pub fn validate_create_link_actant_updates(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
+    let previous_revision_hash = match record.action() {
+        Action::Update(update) => update.original_action_address.clone(),
+        _ => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("ActantUpdates links must point to an update of the base Actant"),
+                ),
+            );
+        }
+    };
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ActantUpdates links must be created by the author of the update"),
+            ),
+        );
+    }
+    // The update must chain back to the base, and its author must be a member of the
+    // revision it updates
+    let previous_actant = match must_get_actant_revision(
+        &original_actant_hash,
+        &previous_revision_hash,
+    )? {
+        Some(actant) => actant,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("ActantUpdates links must point to an update of the base Actant"),
+                ),
+            );
+        }
+    };
+    if !is_actant_member(
+        &original_actant_hash,
+        &previous_revision_hash,
+        &previous_actant,
+        &action.author,
+        &action.prev_action,
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only members of the updated revision can create ActantUpdates links"),
+            ),
+        );
+    }
+    if let Some(tag) = ActantUpdateTag::from_tag(tag)? {
+        for merged_revision_hash in tag.merged_revision_hashes {
+            let record = must_get_valid_record(merged_revision_hash)?;
+            if !crate::is_revision_of(&original_actant_hash, record)? {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        String::from("Merged revisions must be updates of the base Actant"),
+                    ),
+                );
+            }
+        }
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_link_actant_updates(
@@ -71,11 +465,10 @@ pub fn validate_delete_link_actant_updates(
 }
 pub fn validate_create_link_all_actants(
     _action: CreateLink,
-    _base_address: AnyLinkableHash,
+    base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // Check the entry type for the given action hash
     let action_hash = ActionHash::from(target_address);
     let record = must_get_valid_record(action_hash)?;
     let _actant: crate::Actant = record
@@ -87,7 +480,14 @@ pub fn validate_create_link_all_actants(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
-    // TODO: add the appropriate validation rules
+    let shard_path = crate::time_shard_path("all_actants", record.action().timestamp());
+    if AnyLinkableHash::from(shard_path.path_entry_hash()?) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("AllActants links must start from the day shard of the Actant's creation"),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_link_all_actants(
@@ -103,3 +503,222 @@ pub fn validate_delete_link_all_actants(
         ),
     )
 }
+pub fn validate_create_link_all_actants_shards(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    crate::validate_time_shard_link("all_actants", &action, &base_address, &target_address, &tag)
+}
+pub fn validate_delete_link_all_actants_shards(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AllActantsShards links cannot be deleted",
+    )))
+}
+/// Tag of an `AgentToActants` link, naming the Actant revision that made the agent a member.
+/// Agents create these links themselves, once they are members of that revision.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+pub struct AgentToActantTag {
+    pub actant_revision_hash: ActionHash,
+}
+pub fn validate_create_link_agent_to_actants(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let tag = AgentToActantTag::try_from(SerializedBytes::from(UnsafeBytes::from(tag.0)))
+        .map_err(|e| wasm_error!(e))?;
+    let actant = match must_get_actant_revision(
+        &ActionHash::from(target_address.clone()),
+        &tag.actant_revision_hash,
+    )? {
+        Some(actant) => actant,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("AgentToActants links must be tagged with a revision of the linked Actant"),
+                ),
+            );
+        }
+    };
+    if AnyLinkableHash::from(action.author.clone()) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("AgentToActants links must be created by the agent they start from"),
+            ),
+        );
+    }
+    let original_actant_hash = ActionHash::from(target_address);
+    if !is_actant_member(
+        &original_actant_hash,
+        &tag.actant_revision_hash,
+        &actant,
+        &action.author,
+        &action.prev_action,
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Agents can only link themselves to Actants they created or accepted an invitation to"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Hashes of the Actant updates on the source chain of `author` up to `chain_top`,
+/// latest first, without fetching the entries of other actions
+fn must_get_actant_updates_by(
+    author: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<Vec<ActionHash>> {
+    let actant_entry_type: EntryType = crate::UnitEntryTypes::Actant.try_into()?;
+    let activity = must_get_agent_activity(
+        author.clone(),
+        ChainFilter::new(chain_top.clone()),
+    )?;
+    let mut updates: Vec<(u32, ActionHash)> = activity
+        .into_iter()
+        .filter_map(|item| match &item.action.hashed.content {
+            Action::Update(update) if update.entry_type == actant_entry_type => {
+                Some((update.action_seq, item.action.hashed.hash.clone()))
+            }
+            _ => None,
+        })
+        .collect();
+    updates.sort_by(|a, b| b.0.cmp(&a.0));
+    Ok(updates.into_iter().map(|(_, update_hash)| update_hash).collect())
+}
+/// The latest update of the Actant authored by `author` on its source chain up to `chain_top`
+fn must_get_latest_actant_update(
+    original_actant_hash: &ActionHash,
+    author: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<Option<Actant>> {
+    for update_hash in must_get_actant_updates_by(author, chain_top)? {
+        if let Some(actant) = must_get_actant_revision(original_actant_hash, &update_hash)? {
+            return Ok(Some(actant));
+        }
+    }
+    Ok(None)
+}
+/// Actant revisions cited on the source chain of `author` up to `chain_top` by its reports,
+/// endorsements, approvals and transitions, by the guardians of its Actants and by the
+/// invitations it accepted
+fn must_get_revisions_cited_by(
+    author: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<Vec<ActionHash>> {
+    let entries = crate::must_get_chain_entries(
+        author,
+        chain_top,
+        &[
+            crate::UnitEntryTypes::Report,
+            crate::UnitEntryTypes::Endorsement,
+            crate::UnitEntryTypes::ClauseApproval,
+            crate::UnitEntryTypes::ClauseTransition,
+            crate::UnitEntryTypes::ActantAcceptance,
+            crate::UnitEntryTypes::Actant,
+        ],
+    )?;
+    let mut revision_hashes: Vec<ActionHash> = Vec::new();
+    for (_, entry) in entries {
+        match entry {
+            crate::EntryTypes::Report(report) => {
+                revision_hashes.extend(report.actant_revision_hash);
+            }
+            crate::EntryTypes::Endorsement(endorsement) => {
+                revision_hashes.extend(endorsement.actant_revision_hashes);
+            }
+            crate::EntryTypes::ClauseApproval(approval) => {
+                revision_hashes.extend(approval.actant_revision_hashes);
+            }
+            crate::EntryTypes::ClauseTransition(transition) => {
+                revision_hashes.extend(transition.actant_revision_hashes);
+            }
+            crate::EntryTypes::Actant(actant) => {
+                revision_hashes.extend(actant.guardian_revision_hashes);
+            }
+            crate::EntryTypes::ActantAcceptance(acceptance) => {
+                let record = must_get_valid_record(acceptance.invitation_hash)?;
+                let invitation: crate::ActantInvitation = record
+                    .entry()
+                    .to_app_option()
+                    .map_err(|e| wasm_error!(e))?
+                    .ok_or(
+                        wasm_error!(
+                            WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                        ),
+                    )?;
+                revision_hashes.push(invitation.actant_revision_hash);
+            }
+            _ => {}
+        }
+    }
+    revision_hashes.sort();
+    revision_hashes.dedup();
+    Ok(revision_hashes)
+}
+/// Whether the source chain of `author` up to `chain_top` shows a later revision of the
+/// Actant than `revision_hash`: an update the author made, a revision it cited or one it
+/// accepted an invitation to. Later revisions the author has never seen, such as another
+/// member removing it, are not visible to validation
+pub fn is_revision_superseded_by(
+    revision_hash: &ActionHash,
+    author: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    let mut later_revision_hashes = must_get_actant_updates_by(author, chain_top)?;
+    later_revision_hashes.extend(must_get_revisions_cited_by(author, chain_top)?);
+    for later_revision_hash in later_revision_hashes {
+        if &later_revision_hash == revision_hash {
+            continue;
+        }
+        if crate::is_revision_of(revision_hash, must_get_valid_record(later_revision_hash)?)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+pub fn validate_delete_link_agent_to_actants(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    // The agent can always remove itself from the index
+    if AnyLinkableHash::from(action.author.clone()) == base {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    // Anyone else must just have updated the Actant to a revision without the agent, which
+    // required being a member of the revision it updated
+    let updated_actant = match must_get_latest_actant_update(
+        &ActionHash::from(target),
+        &action.author,
+        &action.prev_action,
+    )? {
+        Some(actant) => actant,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Only the agent or a member updating the Actant can delete AgentToActants links"),
+                ),
+            );
+        }
+    };
+    if updated_actant.agents.iter().any(|agent| AnyLinkableHash::from(agent.clone()) == base) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("AgentToActants links can only be deleted once the agent is removed from the Actant"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
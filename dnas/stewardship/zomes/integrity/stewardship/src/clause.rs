@@ -6,13 +6,96 @@ pub struct Clause {
     pub statement: String,
     pub right_holders: Vec<ActionHash>,
     pub responsibilty_holders: Vec<ActionHash>,
+    /// Whether the clause needs a countersigned `ClauseRatification` from all its holders
+    /// before it can become active
+    #[serde(default)]
+    pub requires_ratification: bool,
+    /// Number of distinct right holders that must endorse a report for it to be verified
+    #[serde(default = "default_endorsement_quorum")]
+    pub endorsement_quorum: u32,
+    /// Cadence at which responsibility holders must report on the clause, if any
+    #[serde(default)]
+    pub reporting_schedule: Option<ReportingSchedule>,
+}
+/// Responsibility holders owe one report per interval from `start` on, each due by the end
+/// of its interval plus the grace period
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReportingSchedule {
+    pub start: Timestamp,
+    pub interval_secs: u64,
+    pub grace_period_secs: u64,
+}
+/// Latest start of a reporting schedule, 10000-01-01T00:00:00Z
+pub const MAX_SCHEDULE_START_MICROS: i64 = 253_402_300_800_000_000;
+/// Longest interval of a reporting schedule, a hundred years
+pub const MAX_REPORTING_INTERVAL_SECS: u64 = 100 * 366 * 86_400;
+fn validate_reporting_schedule(clause: &Clause) -> Option<ValidateCallbackResult> {
+    let schedule = clause.reporting_schedule.as_ref()?;
+    if schedule.start.as_micros() < 0 || schedule.start.as_micros() > MAX_SCHEDULE_START_MICROS {
+        return Some(ValidateCallbackResult::Invalid(String::from(
+            "A reporting schedule must start between 1970 and 9999",
+        )));
+    }
+    if schedule.interval_secs == 0 {
+        return Some(ValidateCallbackResult::Invalid(String::from(
+            "A reporting schedule must have a positive interval",
+        )));
+    }
+    if schedule.interval_secs > MAX_REPORTING_INTERVAL_SECS {
+        return Some(ValidateCallbackResult::Invalid(String::from(
+            "The interval of a reporting schedule cannot exceed a hundred years",
+        )));
+    }
+    if schedule.grace_period_secs >= schedule.interval_secs {
+        return Some(ValidateCallbackResult::Invalid(String::from(
+            "The grace period of a reporting schedule must be shorter than its interval",
+        )));
+    }
+    None
+}
+fn default_endorsement_quorum() -> u32 {
+    1
 }
 pub fn validate_create_clause(
     _action: EntryCreationAction,
     clause: Clause,
 ) -> ExternResult<ValidateCallbackResult> {
-    for action_hash in clause.responsibilty_holders.clone() {
-        let record = must_get_valid_record(action_hash)?;
+    if clause.right_holders.is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "A clause must have at least one right holder",
+        )));
+    }
+    if clause.responsibilty_holders.is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "A clause must have at least one responsibility holder",
+        )));
+    }
+    if clause.endorsement_quorum == 0
+        || clause.endorsement_quorum as usize > clause.right_holders.len()
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "The endorsement quorum must be between one and the number of right holders",
+        )));
+    }
+    if let Some(invalid) = validate_reporting_schedule(&clause) {
+        return Ok(invalid);
+    }
+    if has_duplicates(&clause.right_holders) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "A clause cannot list the same right holder twice",
+        )));
+    }
+    if has_duplicates(&clause.responsibilty_holders) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "A clause cannot list the same responsibility holder twice",
+        )));
+    }
+    for action_hash in clause
+        .responsibilty_holders
+        .iter()
+        .chain(clause.right_holders.iter())
+    {
+        let record = must_get_valid_record(action_hash.clone())?;
         let _actant: crate::Actant = record
             .entry()
             .to_app_option()
@@ -23,15 +106,77 @@ pub fn validate_create_clause(
     }
     Ok(ValidateCallbackResult::Valid)
 }
+fn has_duplicates(hashes: &[ActionHash]) -> bool {
+    hashes
+        .iter()
+        .enumerate()
+        .any(|(i, hash)| hashes[..i].contains(hash))
+}
+/// Role an Actant holds on a clause, stored as the tag of its `ActantToClauses` link
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClauseRole {
+    RightHolder,
+    ResponsibilityHolder,
+}
+impl ClauseRole {
+    pub fn tag(&self) -> LinkTag {
+        match self {
+            ClauseRole::RightHolder => LinkTag::new("right_holder"),
+            ClauseRole::ResponsibilityHolder => LinkTag::new("responsibility_holder"),
+        }
+    }
+    pub fn from_tag(tag: &LinkTag) -> Option<ClauseRole> {
+        [ClauseRole::RightHolder, ClauseRole::ResponsibilityHolder]
+            .into_iter()
+            .find(|role| role.tag() == *tag)
+    }
+}
+/// Tag of a `ClauseUpdates` link, carrying the approvals that accepted the amendment
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+pub struct ClauseUpdateTag {
+    pub approval_hashes: Vec<ActionHash>,
+    /// The `ClauseUpdates` link that accepted the amended revision, required unless the
+    /// amendment is made to the original clause
+    #[serde(default)]
+    pub previous_acceptance_hash: Option<ActionHash>,
+}
 pub fn validate_update_clause(
-    _action: Update,
-    _clause: Clause,
+    action: Update,
+    clause: Clause,
     _original_action: EntryCreationAction,
-    _original_clause: Clause,
+    original_clause: Clause,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Invalid(String::from(
-        "Clauses cannot be updated",
-    )))
+    if clause.right_holders != original_clause.right_holders
+        || clause.responsibilty_holders != original_clause.responsibilty_holders
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Clause amendments cannot change the right or responsibility holders",
+        )));
+    }
+    if clause.requires_ratification != original_clause.requires_ratification {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Clause amendments cannot change whether the clause requires ratification",
+        )));
+    }
+    if clause.endorsement_quorum != original_clause.endorsement_quorum {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Clause amendments cannot change the endorsement quorum",
+        )));
+    }
+    if let Some(invalid) = validate_reporting_schedule(&clause) {
+        return Ok(invalid);
+    }
+    if !crate::is_member_of_any_actant(
+        &original_clause.responsibilty_holders,
+        &[],
+        &action.author,
+        &action.prev_action,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only agents of a responsibility holder can propose a clause amendment",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_clause(
     _action: Delete,
@@ -41,13 +186,13 @@ pub fn validate_delete_clause(
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_create_link_actant_to_clauses(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
-    _tag: LinkTag,
+    tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::from(base_address);
-    let record = must_get_valid_record(action_hash)?;
+    let actant_hash = ActionHash::from(base_address);
+    let record = must_get_valid_record(actant_hash.clone())?;
     let _actant: crate::Actant = record
         .entry()
         .to_app_option()
@@ -57,13 +202,32 @@ pub fn validate_create_link_actant_to_clauses(
         ))))?;
     let action_hash = ActionHash::from(target_address);
     let record = must_get_valid_record(action_hash)?;
-    let _clause: crate::Clause = record
+    let clause: crate::Clause = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
         .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
             "Linked action must reference an entry"
         ))))?;
+    let holders = match ClauseRole::from_tag(&tag) {
+        Some(ClauseRole::RightHolder) => clause.right_holders,
+        Some(ClauseRole::ResponsibilityHolder) => clause.responsibilty_holders,
+        None => {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "ActantToClauses links must be tagged with a clause role",
+            )));
+        }
+    };
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "ActantToClauses links must be created by the author of the clause",
+        )));
+    }
+    if !holders.contains(&actant_hash) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "The Actant does not hold the role of the ActantToClauses link on the clause",
+        )));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_link_actant_to_clauses(
@@ -79,7 +243,7 @@ pub fn validate_delete_link_actant_to_clauses(
 }
 pub fn validate_create_link_all_clauses(
     _action: CreateLink,
-    _base_address: AnyLinkableHash,
+    base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
@@ -92,6 +256,12 @@ pub fn validate_create_link_all_clauses(
         .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
             "Linked action must reference an entry"
         ))))?;
+    let shard_path = crate::time_shard_path("all_clauses", record.action().timestamp());
+    if AnyLinkableHash::from(shard_path.path_entry_hash()?) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "AllClauses links must start from the day shard of the clause's creation",
+        )));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_link_all_clauses(
@@ -105,3 +275,159 @@ pub fn validate_delete_link_all_clauses(
         "AllClauses links cannot be deleted",
     )))
 }
+pub fn validate_create_link_clause_proposals(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let _clause: crate::Clause = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must reference an entry"
+        ))))?;
+    if !crate::is_revision_of(&ActionHash::from(base_address), record)? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "ClauseProposals links must point to an amendment of the base clause",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_clause_proposals(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "ClauseProposals links cannot be deleted",
+    )))
+}
+pub fn validate_create_link_clause_updates(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let revision_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(revision_hash.clone())?;
+    let _clause: crate::Clause = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must reference an entry"
+        ))))?;
+    let previous_clause_hash = match record.action() {
+        Action::Update(update) => update.original_action_address.clone(),
+        _ => {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "ClauseUpdates links must point to a clause amendment",
+            )));
+        }
+    };
+    if !crate::is_revision_of(&ActionHash::from(base_address.clone()), record)? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "ClauseUpdates links must point to an amendment of the base clause",
+        )));
+    }
+    let tag = ClauseUpdateTag::try_from(SerializedBytes::from(UnsafeBytes::from(tag.0)))
+        .map_err(|e| wasm_error!(e))?;
+    if AnyLinkableHash::from(previous_clause_hash.clone()) != base_address
+        && !is_accepted_by(&base_address, &previous_clause_hash, &tag.previous_acceptance_hash)?
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only amendments of the original clause or of an accepted amendment can be accepted",
+        )));
+    }
+    let record = must_get_valid_record(previous_clause_hash)?;
+    let previous_clause: crate::Clause = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Dependant action must be accompanied by an entry"
+        ))))?;
+    let mut approving_actants: Vec<ActionHash> = Vec::new();
+    for approval_hash in tag.approval_hashes {
+        let record = must_get_valid_record(approval_hash)?;
+        let approval: crate::ClauseApproval = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Dependant action must be accompanied by an entry"
+            ))))?;
+        if approval.clause_revision_hash == revision_hash {
+            approving_actants.push(approval.actant_hash);
+        }
+    }
+    if !previous_clause
+        .responsibilty_holders
+        .iter()
+        .all(|actant_hash| approving_actants.contains(actant_hash))
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "A clause amendment must be approved by every responsibility holder",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Whether `acceptance_hash` is a `ClauseUpdates` link from the clause at `base_address`
+/// that accepted `revision_hash`
+fn is_accepted_by(
+    base_address: &AnyLinkableHash,
+    revision_hash: &ActionHash,
+    acceptance_hash: &Option<ActionHash>,
+) -> ExternResult<bool> {
+    let acceptance_hash = match acceptance_hash {
+        Some(acceptance_hash) => acceptance_hash.clone(),
+        None => return Ok(false),
+    };
+    let record = must_get_valid_record(acceptance_hash)?;
+    let create_link = match record.action() {
+        Action::CreateLink(create_link) => create_link.clone(),
+        _ => return Ok(false),
+    };
+    let link_type = crate::LinkTypes::from_type(create_link.zome_index, create_link.link_type)?;
+    Ok(
+        matches!(link_type, Some(crate::LinkTypes::ClauseUpdates))
+            && &create_link.base_address == base_address
+            && create_link.target_address == AnyLinkableHash::from(revision_hash.clone())
+    )
+}
+pub fn validate_delete_link_clause_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "ClauseUpdates links cannot be deleted",
+    )))
+}
+pub fn validate_create_link_all_clauses_shards(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    crate::validate_time_shard_link("all_clauses", &action, &base_address, &target_address, &tag)
+}
+pub fn validate_delete_link_all_clauses_shards(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AllClausesShards links cannot be deleted",
+    )))
+}
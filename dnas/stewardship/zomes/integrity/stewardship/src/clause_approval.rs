@@ -0,0 +1,128 @@
+use hdi::prelude::*;
+/// Approval of a proposed clause amendment on behalf of one of its responsibility holders
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ClauseApproval {
+    pub clause_revision_hash: ActionHash,
+    pub actant_hash: ActionHash,
+    /// Revisions of the approving Actant and its guardians the author acts as, filled with
+    /// the latest ones by `approve_clause_amendment`
+    #[serde(default)]
+    pub actant_revision_hashes: Vec<ActionHash>,
+}
+pub fn validate_create_clause_approval(
+    action: EntryCreationAction,
+    clause_approval: ClauseApproval,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(clause_approval.clause_revision_hash.clone())?;
+    let _clause: crate::Clause = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    let previous_clause_hash = match record.action() {
+        Action::Update(update) => update.original_action_address.clone(),
+        _ => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Only clause amendments can be approved"),
+                ),
+            );
+        }
+    };
+    let record = must_get_valid_record(previous_clause_hash)?;
+    let previous_clause: crate::Clause = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if !previous_clause.responsibilty_holders.contains(&clause_approval.actant_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only responsibility holders of the clause can approve its amendments"),
+            ),
+        );
+    }
+    if !crate::is_member_of_any_actant(
+        &[clause_approval.actant_hash],
+        &clause_approval.actant_revision_hashes,
+        action.author(),
+        action.prev_action(),
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only agents of an Actant can approve on its behalf"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_clause_approval(
+    _action: Update,
+    _clause_approval: ClauseApproval,
+    _original_action: EntryCreationAction,
+    _original_clause_approval: ClauseApproval,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Clause approvals cannot be updated")))
+}
+pub fn validate_delete_clause_approval(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_clause_approval: ClauseApproval,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Clause approvals cannot be deleted")))
+}
+pub fn validate_create_link_clause_approvals(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let clause_approval: crate::ClauseApproval = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if AnyLinkableHash::from(clause_approval.clause_revision_hash) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ClauseApprovals links must start from the approved clause amendment"),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ClauseApprovals links must be created by the author of the approval"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_clause_approvals(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("ClauseApprovals links cannot be deleted"),
+        ),
+    )
+}
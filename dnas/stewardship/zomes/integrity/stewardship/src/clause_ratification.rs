@@ -0,0 +1,218 @@
+use hdi::prelude::*;
+/// Agreement of every holder of a clause, committed through a countersigning session
+/// in which one agent of each holder Actant signs the same action. The revisions of the
+/// holders and their guardians the signers act as travel in the preflight bytes of the
+/// session, as `RatificationRevisions`, since the entry is hashed before it starts.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ClauseRatification {
+    pub clause_hash: ActionHash,
+}
+/// Contents of the preflight bytes of a ratification session
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RatificationRevisions {
+    pub actant_revision_hashes: Vec<ActionHash>,
+}
+/// Fetches the ratification at `ratification_hash`. Its record holds the countersigned
+/// entry, which `to_app_option` does not decode.
+pub fn must_get_clause_ratification(
+    ratification_hash: ActionHash,
+) -> ExternResult<Option<ClauseRatification>> {
+    let record = must_get_valid_record(ratification_hash)?;
+    match record.entry().as_option() {
+        Some(Entry::CounterSign(_, app_entry_bytes)) => {
+            let ratification = ClauseRatification::try_from(
+                    app_entry_bytes.clone().into_sb(),
+                )
+                .map_err(|e| wasm_error!(e))?;
+            Ok(Some(ratification))
+        }
+        _ => Ok(None),
+    }
+}
+/// The flattened op only exposes the app entry of a countersigned action, so the session
+/// data proving who ratified a clause is checked on the raw op before dispatching it
+pub fn validate_countersigned_op(op: &Op) -> ExternResult<Option<ValidateCallbackResult>> {
+    let (action, entry): (EntryCreationAction, Entry) = match op {
+        Op::StoreEntry(StoreEntry { action, entry }) => {
+            (action.hashed.content.clone(), entry.clone())
+        }
+        Op::StoreRecord(StoreRecord { record }) => {
+            let action = match EntryCreationAction::try_from(record.action().clone()) {
+                Ok(action) => action,
+                Err(_) => return Ok(None),
+            };
+            match record.entry().as_option() {
+                Some(entry) => (action, entry.clone()),
+                None => return Ok(None),
+            }
+        }
+        _ => return Ok(None),
+    };
+    let (zome_index, entry_index) = match action.entry_type() {
+        EntryType::App(AppEntryDef { zome_index, entry_index, .. }) => {
+            (*zome_index, *entry_index)
+        }
+        _ => return Ok(None),
+    };
+    let ratification = match crate::EntryTypes::deserialize_from_type(
+        zome_index,
+        entry_index,
+        &entry,
+    )? {
+        Some(crate::EntryTypes::ClauseRatification(ratification)) => ratification,
+        _ => return Ok(None),
+    };
+    let session_data = match entry {
+        Entry::CounterSign(session_data, _) => session_data,
+        _ => {
+            return Ok(
+                Some(
+                    ValidateCallbackResult::Invalid(
+                        String::from("Clause ratifications must be countersigned"),
+                    ),
+                ),
+            );
+        }
+    };
+    let record = must_get_valid_record(ratification.clause_hash)?;
+    let clause: crate::Clause = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    let revisions: RatificationRevisions = match decode(
+        &session_data.preflight_request().preflight_bytes().0,
+    ) {
+        Ok(revisions) => revisions,
+        Err(_) => {
+            return Ok(
+                Some(
+                    ValidateCallbackResult::Invalid(
+                        String::from("The ratification session must cite the revisions of the holders"),
+                    ),
+                ),
+            );
+        }
+    };
+    let signing_agents = session_data.preflight_request().signing_agents();
+    let mut signers: Vec<(AgentPubKey, ActionHash)> = Vec::new();
+    for (agent_state, _signature) in session_data.responses() {
+        if let Some((agent, _roles)) = signing_agents.get(*agent_state.agent_index() as usize) {
+            signers.push((agent.clone(), agent_state.chain_top().clone()));
+        }
+    }
+    for holder_hash in clause.right_holders.iter().chain(clause.responsibilty_holders.iter()) {
+        let mut has_signed = false;
+        for (agent, chain_top) in signers.iter() {
+            if crate::is_authorised_for_any_actant(
+                &[holder_hash.clone()],
+                &revisions.actant_revision_hashes,
+                agent,
+                chain_top,
+            )? {
+                has_signed = true;
+                break;
+            }
+        }
+        if !has_signed {
+            return Ok(
+                Some(
+                    ValidateCallbackResult::Invalid(
+                        String::from("An agent of every holder Actant must countersign the ratification"),
+                    ),
+                ),
+            );
+        }
+    }
+    Ok(None)
+}
+pub fn validate_create_clause_ratification(
+    _action: EntryCreationAction,
+    clause_ratification: ClauseRatification,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(clause_ratification.clause_hash.clone())?;
+    let clause: crate::Clause = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if !clause.requires_ratification {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only clauses in ratified mode can be ratified"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_clause_ratification(
+    _action: Update,
+    _clause_ratification: ClauseRatification,
+    _original_action: EntryCreationAction,
+    _original_clause_ratification: ClauseRatification,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Clause ratifications cannot be updated")))
+}
+pub fn validate_delete_clause_ratification(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_clause_ratification: ClauseRatification,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Clause ratifications cannot be deleted")))
+}
+pub fn validate_create_link_clause_to_ratifications(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash.clone())?;
+    let clause_ratification = match must_get_clause_ratification(action_hash)? {
+        Some(clause_ratification) => clause_ratification,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("ClauseToRatifications links must point to a countersigned ratification"),
+                ),
+            );
+        }
+    };
+    if AnyLinkableHash::from(clause_ratification.clause_hash) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ClauseToRatifications links must start from the ratified clause"),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ClauseToRatifications links must be created by a signer of the ratification"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_clause_to_ratifications(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("ClauseToRatifications links cannot be deleted"),
+        ),
+    )
+}
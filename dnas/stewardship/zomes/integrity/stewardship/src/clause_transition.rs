@@ -0,0 +1,324 @@
+use hdi::prelude::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ClauseStatus {
+    Draft,
+    Proposed,
+    Active,
+    Suspended,
+    Terminated,
+}
+/// Moves a clause from one lifecycle status to the next. Transitions form a chain through
+/// `previous_transition_hash`, starting from a clause in `Draft` status, and each must
+/// extend the latest transition of the clause on its author's chain.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ClauseTransition {
+    pub clause_hash: ActionHash,
+    pub previous_transition_hash: Option<ActionHash>,
+    pub from: ClauseStatus,
+    pub to: ClauseStatus,
+    /// Ratification of the clause, required to activate a clause in ratified mode
+    #[serde(default)]
+    pub ratification_hash: Option<ActionHash>,
+    /// Revisions of the holders and their guardians the author acts as, filled with the
+    /// latest ones by `transition_clause`
+    #[serde(default)]
+    pub actant_revision_hashes: Vec<ActionHash>,
+}
+/// Who may move a clause along a lifecycle transition
+enum TransitionAuthority {
+    ClauseAuthor,
+    RightHolders,
+    ResponsibilityHolders,
+    AnyHolder,
+}
+fn transition_authority(from: &ClauseStatus, to: &ClauseStatus) -> Option<TransitionAuthority> {
+    match (from, to) {
+        (ClauseStatus::Draft, ClauseStatus::Proposed) => Some(TransitionAuthority::ClauseAuthor),
+        (ClauseStatus::Proposed, ClauseStatus::Active) => {
+            Some(TransitionAuthority::ResponsibilityHolders)
+        }
+        (ClauseStatus::Active, ClauseStatus::Suspended) => {
+            Some(TransitionAuthority::RightHolders)
+        }
+        (ClauseStatus::Suspended, ClauseStatus::Active) => {
+            Some(TransitionAuthority::RightHolders)
+        }
+        (ClauseStatus::Active, ClauseStatus::Terminated)
+        | (ClauseStatus::Suspended, ClauseStatus::Terminated) => {
+            Some(TransitionAuthority::AnyHolder)
+        }
+        _ => None,
+    }
+}
+/// Transitions of the clause on the source chain of `author` up to `chain_top`, both
+/// those it committed and those its reports cited
+fn must_get_transitions_seen_by(
+    clause_hash: &ActionHash,
+    author: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<Vec<ActionHash>> {
+    let entries = crate::must_get_chain_entries(
+        author,
+        chain_top,
+        &[crate::UnitEntryTypes::ClauseTransition, crate::UnitEntryTypes::Report],
+    )?;
+    let mut transition_hashes = Vec::new();
+    for (action_hash, entry) in entries {
+        match entry {
+            crate::EntryTypes::ClauseTransition(transition) if transition.clause_hash == *clause_hash => {
+                transition_hashes.push(action_hash);
+            }
+            crate::EntryTypes::Report(report) if report.clause_hash == *clause_hash => {
+                transition_hashes.extend(report.clause_transition_hash);
+            }
+            _ => {}
+        }
+    }
+    transition_hashes.sort();
+    transition_hashes.dedup();
+    Ok(transition_hashes)
+}
+fn must_get_clause_transition(transition_hash: ActionHash) -> ExternResult<ClauseTransition> {
+    let record = must_get_valid_record(transition_hash)?;
+    record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )
+}
+/// Whether the source chain of `author` up to `chain_top` shows a transition of the clause
+/// following the given one, `None` standing for the draft, directly or through later
+/// transitions. Transitions by other agents that the author has never seen are not
+/// visible to validation
+pub fn is_transition_superseded_by(
+    clause_hash: &ActionHash,
+    transition_hash: Option<&ActionHash>,
+    author: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    for seen_hash in must_get_transitions_seen_by(clause_hash, author, chain_top)? {
+        let mut previous_transition_hash = must_get_clause_transition(seen_hash)?
+            .previous_transition_hash;
+        loop {
+            if previous_transition_hash.as_ref() == transition_hash {
+                return Ok(true);
+            }
+            previous_transition_hash = match previous_transition_hash {
+                Some(previous_hash) => {
+                    must_get_clause_transition(previous_hash)?.previous_transition_hash
+                }
+                None => break,
+            };
+        }
+    }
+    Ok(false)
+}
+pub fn validate_create_clause_transition(
+    action: EntryCreationAction,
+    clause_transition: ClauseTransition,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(clause_transition.clause_hash.clone())?;
+    let clause: crate::Clause = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if let Action::Update(_) = record.action() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Transitions must refer to the original clause"),
+            ),
+        );
+    }
+    let clause_author = record.action().author().clone();
+    let previous_status = match clause_transition.previous_transition_hash.clone() {
+        None => ClauseStatus::Draft,
+        Some(previous_transition_hash) => {
+            let record = must_get_valid_record(previous_transition_hash)?;
+            let previous_transition: crate::ClauseTransition = record
+                .entry()
+                .to_app_option()
+                .map_err(|e| wasm_error!(e))?
+                .ok_or(
+                    wasm_error!(
+                        WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                    ),
+                )?;
+            if previous_transition.clause_hash != clause_transition.clause_hash {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        String::from("The previous transition must belong to the same clause"),
+                    ),
+                );
+            }
+            previous_transition.to
+        }
+    };
+    if previous_status != clause_transition.from {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("A transition must start from the status the previous one ended in"),
+            ),
+        );
+    }
+    // Only the author's own chain is visible here: two agents that have not seen each
+    // other's transitions can still fork the lifecycle, and readers follow the branch
+    // with the lowest action hash
+    if is_transition_superseded_by(
+        &clause_transition.clause_hash,
+        clause_transition.previous_transition_hash.as_ref(),
+        action.author(),
+        action.prev_action(),
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("A transition must extend the latest transition its author has seen"),
+            ),
+        );
+    }
+    let authority = match transition_authority(
+        &clause_transition.from,
+        &clause_transition.to,
+    ) {
+        Some(authority) => authority,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    format!(
+                        "A clause cannot move from {:?} to {:?}", clause_transition.from,
+                        clause_transition.to
+                    ),
+                ),
+            );
+        }
+    };
+    if clause.requires_ratification && clause_transition.from == ClauseStatus::Proposed
+        && clause_transition.to == ClauseStatus::Active
+    {
+        let ratification = match clause_transition.ratification_hash.clone() {
+            Some(ratification_hash) => crate::must_get_clause_ratification(ratification_hash)?,
+            None => None,
+        };
+        if ratification.map_or(true, |r| r.clause_hash != clause_transition.clause_hash) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("A clause in ratified mode can only be activated with its ratification"),
+                ),
+            );
+        }
+    }
+    let is_authorised = match authority {
+        TransitionAuthority::ClauseAuthor => *action.author() == clause_author,
+        TransitionAuthority::RightHolders => {
+            crate::is_authorised_for_any_actant(
+                &clause.right_holders,
+                &clause_transition.actant_revision_hashes,
+                action.author(),
+                action.prev_action(),
+            )?
+        }
+        TransitionAuthority::ResponsibilityHolders => {
+            crate::is_authorised_for_any_actant(
+                &clause.responsibilty_holders,
+                &clause_transition.actant_revision_hashes,
+                action.author(),
+                action.prev_action(),
+            )?
+        }
+        TransitionAuthority::AnyHolder => {
+            crate::is_authorised_for_any_actant(
+                &clause.right_holders,
+                &clause_transition.actant_revision_hashes,
+                action.author(),
+                action.prev_action(),
+            )?
+                || crate::is_authorised_for_any_actant(
+                    &clause.responsibilty_holders,
+                    &clause_transition.actant_revision_hashes,
+                    action.author(),
+                    action.prev_action(),
+                )?
+        }
+    };
+    if !is_authorised {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                format!(
+                    "The author is not allowed to move the clause from {:?} to {:?}",
+                    clause_transition.from, clause_transition.to
+                ),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_clause_transition(
+    _action: Update,
+    _clause_transition: ClauseTransition,
+    _original_action: EntryCreationAction,
+    _original_clause_transition: ClauseTransition,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Clause transitions cannot be updated")))
+}
+pub fn validate_delete_clause_transition(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_clause_transition: ClauseTransition,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Clause transitions cannot be deleted")))
+}
+pub fn validate_create_link_clause_to_transitions(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let clause_transition: crate::ClauseTransition = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if AnyLinkableHash::from(clause_transition.clause_hash) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ClauseToTransitions links must start from the transitioned clause"),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ClauseToTransitions links must be created by the author of the transition"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_clause_to_transitions(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("ClauseToTransitions links cannot be deleted"),
+        ),
+    )
+}
@@ -1,15 +1,92 @@
 use hdi::prelude::*;
+/// Highest confidence an endorser can state, as a percentage
+pub const MAX_CONFIDENCE: u8 = 100;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub enum EndorsementStance {
+    #[default]
+    Endorse,
+    PartiallyEndorse,
+    Dispute,
+}
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct Endorsement {
     pub report_hash: ActionHash,
+    #[serde(default)]
+    pub stance: EndorsementStance,
+    /// Required to partially endorse or dispute a report
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// How confident the endorser is in its stance, from 0 to `MAX_CONFIDENCE`
+    #[serde(default = "max_confidence")]
+    pub confidence: u8,
+    /// Right holder of the clause the endorser speaks for. Required by validation, and
+    /// filled by `create_endorsement` with the first one the endorser can act for when
+    /// left empty
+    #[serde(default)]
+    pub actant_hash: Option<ActionHash>,
+    /// Revisions of the right holder and its guardians the endorser acts as, filled with
+    /// the latest ones by `create_endorsement`
+    #[serde(default)]
+    pub actant_revision_hashes: Vec<ActionHash>,
+}
+fn max_confidence() -> u8 {
+    MAX_CONFIDENCE
+}
+fn validate_assessment(endorsement: &Endorsement) -> Option<ValidateCallbackResult> {
+    if endorsement.confidence > MAX_CONFIDENCE {
+        return Some(
+            ValidateCallbackResult::Invalid(
+                format!("The confidence of an endorsement cannot exceed {}", MAX_CONFIDENCE),
+            ),
+        );
+    }
+    let has_comment = endorsement
+        .comment
+        .as_ref()
+        .map_or(false, |comment| !comment.trim().is_empty());
+    if endorsement.comment.is_some() && !has_comment {
+        return Some(
+            ValidateCallbackResult::Invalid(
+                String::from("An endorsement comment cannot be empty"),
+            ),
+        );
+    }
+    if endorsement.stance != EndorsementStance::Endorse && !has_comment {
+        return Some(
+            ValidateCallbackResult::Invalid(
+                String::from("Partial endorsements and disputes must explain themselves in a comment"),
+            ),
+        );
+    }
+    None
 }
 pub fn validate_create_endorsement(
-    _action: EntryCreationAction,
+    action: EntryCreationAction,
     endorsement: Endorsement,
 ) -> ExternResult<ValidateCallbackResult> {
     let record = must_get_valid_record(endorsement.report_hash.clone())?;
-    let _report: crate::Report = record
+    let report: crate::Report = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if let Some(invalid) = validate_assessment(&endorsement) {
+        return Ok(invalid);
+    }
+    if record.action().author() == action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Reports cannot be endorsed by their author"),
+            ),
+        );
+    }
+    let record = must_get_valid_record(report.clause_hash.clone())?;
+    let clause: crate::Clause = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -18,8 +95,70 @@ pub fn validate_create_endorsement(
                 WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
             ),
         )?;
+    let actant_hash = match &endorsement.actant_hash {
+        Some(actant_hash) => actant_hash.clone(),
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Endorsements must name the right holder they speak for"),
+                ),
+            );
+        }
+    };
+    if !clause.right_holders.contains(&actant_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Endorsements can only be made on behalf of a right holder of the clause"),
+            ),
+        );
+    }
+    if !crate::is_authorised_for_any_actant(
+        &[actant_hash],
+        &endorsement.actant_revision_hashes,
+        action.author(),
+        action.prev_action(),
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only members or guardians of a right holder of the clause can endorse its reports"),
+            ),
+        );
+    }
+    // Walking the author's chain is the costliest check, so it runs last
+    if has_endorsed_report(&action, &endorsement.report_hash)? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("An agent can only have one unrevoked endorsement of a report"),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
+/// Looks for an earlier endorsement of `report_hash` on the author's source chain that
+/// the author has not revoked since
+fn has_endorsed_report(
+    action: &EntryCreationAction,
+    report_hash: &ActionHash,
+) -> ExternResult<bool> {
+    let mut endorsement_hashes: Vec<ActionHash> = Vec::new();
+    let mut revoked: Vec<ActionHash> = Vec::new();
+    for (action_hash, entry) in crate::must_get_chain_entries(
+        action.author(),
+        action.prev_action(),
+        &[crate::UnitEntryTypes::Endorsement, crate::UnitEntryTypes::EndorsementRevocation],
+    )? {
+        match entry {
+            crate::EntryTypes::Endorsement(endorsement) if endorsement.report_hash == *report_hash => {
+                endorsement_hashes.push(action_hash);
+            }
+            crate::EntryTypes::EndorsementRevocation(revocation) => {
+                revoked.push(revocation.endorsement_hash);
+            }
+            _ => {}
+        }
+    }
+    Ok(endorsement_hashes.iter().any(|endorsement_hash| !revoked.contains(endorsement_hash)))
+}
 pub fn validate_update_endorsement(
     _action: Update,
     _endorsement: Endorsement,
@@ -36,12 +175,12 @@ pub fn validate_delete_endorsement(
     Ok(ValidateCallbackResult::Invalid(String::from("Endorsements cannot be deleted")))
 }
 pub fn validate_create_link_report_to_endorsements(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::from(base_address);
+    let action_hash = ActionHash::from(base_address.clone());
     let record = must_get_valid_record(action_hash)?;
     let _report: crate::Report = record
         .entry()
@@ -54,7 +193,7 @@ pub fn validate_create_link_report_to_endorsements(
         )?;
     let action_hash = ActionHash::from(target_address);
     let record = must_get_valid_record(action_hash)?;
-    let _endorsement: crate::Endorsement = record
+    let endorsement: crate::Endorsement = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -63,6 +202,20 @@ pub fn validate_create_link_report_to_endorsements(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
+    if AnyLinkableHash::from(endorsement.report_hash) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ReportToEndorsements links must start from the endorsed report"),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ReportToEndorsements links must be created by the author of the endorsement"),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_link_report_to_endorsements(
@@ -0,0 +1,92 @@
+use hdi::prelude::*;
+/// Withdraws an endorsement. The endorser may endorse the report again afterwards.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct EndorsementRevocation {
+    pub endorsement_hash: ActionHash,
+    pub reason: Option<String>,
+}
+pub fn validate_create_endorsement_revocation(
+    action: EntryCreationAction,
+    endorsement_revocation: EndorsementRevocation,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(endorsement_revocation.endorsement_hash.clone())?;
+    let _endorsement: crate::Endorsement = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if record.action().author() != action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the endorser can revoke an endorsement"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_endorsement_revocation(
+    _action: Update,
+    _endorsement_revocation: EndorsementRevocation,
+    _original_action: EntryCreationAction,
+    _original_endorsement_revocation: EndorsementRevocation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Endorsement revocations cannot be updated")))
+}
+pub fn validate_delete_endorsement_revocation(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_endorsement_revocation: EndorsementRevocation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Endorsement revocations cannot be deleted")))
+}
+pub fn validate_create_link_endorsement_to_revocations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let endorsement_revocation: crate::EndorsementRevocation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if AnyLinkableHash::from(endorsement_revocation.endorsement_hash) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("EndorsementToRevocations links must start from the revoked endorsement"),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("EndorsementToRevocations links must be created by the author of the revocation"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_endorsement_to_revocations(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("EndorsementToRevocations links cannot be deleted"),
+        ),
+    )
+}
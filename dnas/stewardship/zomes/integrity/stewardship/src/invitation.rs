@@ -0,0 +1,301 @@
+use hdi::prelude::*;
+/// Invitation issued by a member of an Actant to one of the agents listed in a revision of it
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ActantInvitation {
+    pub actant_hash: ActionHash,
+    pub actant_revision_hash: ActionHash,
+    pub invitee: AgentPubKey,
+}
+/// Acceptance of an invitation by its invitee, committed to the invitee's own source chain
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ActantAcceptance {
+    pub invitation_hash: ActionHash,
+    pub actant_hash: ActionHash,
+}
+/// Refusal of an invitation by its invitee, committed to the invitee's own source chain so
+/// that the invitation can no longer be accepted
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ActantDecline {
+    pub invitation_hash: ActionHash,
+    pub actant_hash: ActionHash,
+}
+/// Whether `agent` has accepted or declined the invitation on its source chain up to
+/// `chain_top`, as the given entry type
+fn has_answered_invitation(
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+    invitation_hash: &ActionHash,
+    entry_type: crate::UnitEntryTypes,
+) -> ExternResult<bool> {
+    let entries = crate::must_get_chain_entries(agent, chain_top, &[entry_type])?;
+    Ok(
+        entries
+            .into_iter()
+            .any(|(_, entry)| match entry {
+                crate::EntryTypes::ActantAcceptance(acceptance) => {
+                    acceptance.invitation_hash == *invitation_hash
+                }
+                crate::EntryTypes::ActantDecline(decline) => {
+                    decline.invitation_hash == *invitation_hash
+                }
+                _ => false,
+            }),
+    )
+}
+/// Looks for an acceptance on `agent`'s source chain of an invitation to one of the given
+/// revisions of `actant_hash`, so that an agent removed and added again must accept anew
+pub fn has_accepted_invitation(
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+    actant_hash: &ActionHash,
+    revision_hashes: &[ActionHash],
+) -> ExternResult<bool> {
+    let entries = crate::must_get_chain_entries(
+        agent,
+        chain_top,
+        &[crate::UnitEntryTypes::ActantAcceptance],
+    )?;
+    for (_, entry) in entries {
+        let acceptance = match entry {
+            crate::EntryTypes::ActantAcceptance(acceptance) => acceptance,
+            _ => continue,
+        };
+        if acceptance.actant_hash != *actant_hash {
+            continue;
+        }
+        let record = must_get_valid_record(acceptance.invitation_hash)?;
+        let invitation: crate::ActantInvitation = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                ),
+            )?;
+        if revision_hashes.contains(&invitation.actant_revision_hash) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+pub fn validate_create_actant_invitation(
+    action: EntryCreationAction,
+    actant_invitation: ActantInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    let actant = match crate::must_get_actant_revision(
+        &actant_invitation.actant_hash,
+        &actant_invitation.actant_revision_hash,
+    )? {
+        Some(actant) => actant,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The Actant revision must be an update of the Actant"),
+                ),
+            );
+        }
+    };
+    if !actant.agents.contains(&actant_invitation.invitee) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only agents listed in the Actant revision can be invited"),
+            ),
+        );
+    }
+    if !crate::is_actant_member(
+        &actant_invitation.actant_hash,
+        &actant_invitation.actant_revision_hash,
+        &actant,
+        action.author(),
+        action.prev_action(),
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only members of an Actant can invite agents to it"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_actant_invitation(
+    _action: Update,
+    _actant_invitation: ActantInvitation,
+    _original_action: EntryCreationAction,
+    _original_actant_invitation: ActantInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Invitations cannot be updated")))
+}
+pub fn validate_delete_actant_invitation(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_actant_invitation: ActantInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Invitations cannot be deleted")))
+}
+pub fn validate_create_actant_acceptance(
+    action: EntryCreationAction,
+    actant_acceptance: ActantAcceptance,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(actant_acceptance.invitation_hash.clone())?;
+    let invitation: crate::ActantInvitation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if invitation.actant_hash != actant_acceptance.actant_hash {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The acceptance must refer to the Actant of the invitation"),
+            ),
+        );
+    }
+    if &invitation.invitee != action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the invitee can accept an invitation"),
+            ),
+        );
+    }
+    if has_answered_invitation(
+        action.author(),
+        action.prev_action(),
+        &actant_acceptance.invitation_hash,
+        crate::UnitEntryTypes::ActantDecline,
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("A declined invitation cannot be accepted"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_actant_acceptance(
+    _action: Update,
+    _actant_acceptance: ActantAcceptance,
+    _original_action: EntryCreationAction,
+    _original_actant_acceptance: ActantAcceptance,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Acceptances cannot be updated")))
+}
+pub fn validate_delete_actant_acceptance(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_actant_acceptance: ActantAcceptance,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Acceptances cannot be deleted")))
+}
+pub fn validate_create_actant_decline(
+    action: EntryCreationAction,
+    actant_decline: ActantDecline,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(actant_decline.invitation_hash.clone())?;
+    let invitation: crate::ActantInvitation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if invitation.actant_hash != actant_decline.actant_hash {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The decline must refer to the Actant of the invitation"),
+            ),
+        );
+    }
+    if &invitation.invitee != action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the invitee can decline an invitation"),
+            ),
+        );
+    }
+    if has_answered_invitation(
+        action.author(),
+        action.prev_action(),
+        &actant_decline.invitation_hash,
+        crate::UnitEntryTypes::ActantAcceptance,
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("An accepted invitation cannot be declined, the invitee must leave the Actant instead"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_actant_decline(
+    _action: Update,
+    _actant_decline: ActantDecline,
+    _original_action: EntryCreationAction,
+    _original_actant_decline: ActantDecline,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Declines cannot be updated")))
+}
+pub fn validate_delete_actant_decline(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_actant_decline: ActantDecline,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Declines cannot be deleted")))
+}
+pub fn validate_create_link_agent_to_invitations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let invitation: crate::ActantInvitation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if AnyLinkableHash::from(invitation.invitee) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("AgentToInvitations links must start from the invitee"),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("AgentToInvitations links must be created by the author of the invitation"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_invitations(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if AnyLinkableHash::from(action.author) != base {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the invitee can delete AgentToInvitations links"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -1,3 +1,21 @@
+pub mod endorsement_revocation;
+pub use endorsement_revocation::*;
+pub mod report_retraction;
+pub use report_retraction::*;
+pub mod report_correction;
+pub use report_correction::*;
+pub mod properties;
+pub use properties::*;
+pub mod clause_ratification;
+pub use clause_ratification::*;
+pub mod clause_transition;
+pub use clause_transition::*;
+pub mod invitation;
+pub use invitation::*;
+pub mod time_shard;
+pub use time_shard::*;
+pub mod clause_approval;
+pub use clause_approval::*;
 pub mod endorsement;
 pub use endorsement::*;
 pub mod report;
@@ -16,6 +34,15 @@ pub enum EntryTypes {
     Clause(Clause),
     Report(Report),
     Endorsement(Endorsement),
+    ClauseApproval(ClauseApproval),
+    ActantInvitation(ActantInvitation),
+    ActantAcceptance(ActantAcceptance),
+    ClauseTransition(ClauseTransition),
+    ClauseRatification(ClauseRatification),
+    ReportCorrection(ReportCorrection),
+    ReportRetraction(ReportRetraction),
+    EndorsementRevocation(EndorsementRevocation),
+    ActantDecline(ActantDecline),
 }
 #[derive(Serialize, Deserialize)]
 #[hdk_link_types]
@@ -27,6 +54,18 @@ pub enum LinkTypes {
     ReportToEndorsements,
     AllClauses,
     AllActants,
+    ClauseProposals,
+    ClauseApprovals,
+    ClauseUpdates,
+    AllActantsShards,
+    AllClausesShards,
+    AgentToActants,
+    AgentToInvitations,
+    ClauseToTransitions,
+    ReportToCorrections,
+    ReportToRetractions,
+    EndorsementToRevocations,
+    ClauseToRatifications,
 }
 #[hdk_extern]
 pub fn genesis_self_check(
@@ -40,8 +79,75 @@ pub fn validate_agent_joining(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(ValidateCallbackResult::Valid)
 }
+/// Whether `record` is the action at `original_action_hash` or one of its successive updates
+pub fn is_revision_of(
+    original_action_hash: &ActionHash,
+    record: Record,
+) -> ExternResult<bool> {
+    let mut current = record;
+    while current.action_address() != original_action_hash {
+        match current.action() {
+            Action::Update(update) => {
+                current = must_get_valid_record(update.original_action_address.clone())?;
+            }
+            _ => return Ok(false),
+        }
+    }
+    Ok(true)
+}
+/// Follows the update chain from `action_hash` back to the action that created the entry
+pub fn must_get_original_action_hash(action_hash: ActionHash) -> ExternResult<ActionHash> {
+    let mut current = must_get_valid_record(action_hash)?;
+    while let Action::Update(update) = current.action() {
+        current = must_get_valid_record(update.original_action_address.clone())?;
+    }
+    Ok(current.action_address().clone())
+}
+/// App entries of the given types created by `author` on its source chain, up to and
+/// including `chain_top`, with the hashes of the actions that created them. Only the
+/// entries of actions of those types are fetched.
+pub fn must_get_chain_entries(
+    author: &AgentPubKey,
+    chain_top: &ActionHash,
+    entry_types: &[UnitEntryTypes],
+) -> ExternResult<Vec<(ActionHash, EntryTypes)>> {
+    let entry_types = entry_types
+        .iter()
+        .map(|entry_type| EntryType::try_from(*entry_type))
+        .collect::<ExternResult<Vec<EntryType>>>()?;
+    let activity = must_get_agent_activity(
+        author.clone(),
+        ChainFilter::new(chain_top.clone()),
+    )?;
+    let mut entries = Vec::new();
+    for item in activity {
+        let action_hash = item.action.hashed.hash;
+        let create = match item.action.hashed.content {
+            Action::Create(create) if entry_types.contains(&create.entry_type) => create,
+            _ => continue,
+        };
+        let (zome_index, entry_index) = match create.entry_type {
+            EntryType::App(AppEntryDef { zome_index, entry_index, .. }) => {
+                (zome_index, entry_index)
+            }
+            _ => continue,
+        };
+        let entry = must_get_entry(create.entry_hash)?;
+        if let Some(app_entry) = EntryTypes::deserialize_from_type(
+            zome_index,
+            entry_index,
+            &entry.content,
+        )? {
+            entries.push((action_hash, app_entry));
+        }
+    }
+    Ok(entries)
+}
 #[hdk_extern]
 pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
+    if let Some(invalid) = validate_countersigned_op(&op)? {
+        return Ok(invalid);
+    }
     match op.flattened::<EntryTypes, LinkTypes>()? {
         FlatOp::StoreEntry(store_entry) => {
             match store_entry {
@@ -71,6 +177,60 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 endorsement,
                             )
                         }
+                        EntryTypes::ClauseApproval(clause_approval) => {
+                            validate_create_clause_approval(
+                                EntryCreationAction::Create(action),
+                                clause_approval,
+                            )
+                        }
+                        EntryTypes::ActantInvitation(actant_invitation) => {
+                            validate_create_actant_invitation(
+                                EntryCreationAction::Create(action),
+                                actant_invitation,
+                            )
+                        }
+                        EntryTypes::ActantAcceptance(actant_acceptance) => {
+                            validate_create_actant_acceptance(
+                                EntryCreationAction::Create(action),
+                                actant_acceptance,
+                            )
+                        }
+                        EntryTypes::ActantDecline(actant_decline) => {
+                            validate_create_actant_decline(
+                                EntryCreationAction::Create(action),
+                                actant_decline,
+                            )
+                        }
+                        EntryTypes::ClauseTransition(clause_transition) => {
+                            validate_create_clause_transition(
+                                EntryCreationAction::Create(action),
+                                clause_transition,
+                            )
+                        }
+                        EntryTypes::ClauseRatification(clause_ratification) => {
+                            validate_create_clause_ratification(
+                                EntryCreationAction::Create(action),
+                                clause_ratification,
+                            )
+                        }
+                        EntryTypes::ReportCorrection(report_correction) => {
+                            validate_create_report_correction(
+                                EntryCreationAction::Create(action),
+                                report_correction,
+                            )
+                        }
+                        EntryTypes::ReportRetraction(report_retraction) => {
+                            validate_create_report_retraction(
+                                EntryCreationAction::Create(action),
+                                report_retraction,
+                            )
+                        }
+                        EntryTypes::EndorsementRevocation(endorsement_revocation) => {
+                            validate_create_endorsement_revocation(
+                                EntryCreationAction::Create(action),
+                                endorsement_revocation,
+                            )
+                        }
                     }
                 }
                 OpEntry::UpdateEntry { app_entry, action, .. } => {
@@ -99,6 +259,60 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 endorsement,
                             )
                         }
+                        EntryTypes::ClauseApproval(clause_approval) => {
+                            validate_create_clause_approval(
+                                EntryCreationAction::Update(action),
+                                clause_approval,
+                            )
+                        }
+                        EntryTypes::ActantInvitation(actant_invitation) => {
+                            validate_create_actant_invitation(
+                                EntryCreationAction::Update(action),
+                                actant_invitation,
+                            )
+                        }
+                        EntryTypes::ActantAcceptance(actant_acceptance) => {
+                            validate_create_actant_acceptance(
+                                EntryCreationAction::Update(action),
+                                actant_acceptance,
+                            )
+                        }
+                        EntryTypes::ActantDecline(actant_decline) => {
+                            validate_create_actant_decline(
+                                EntryCreationAction::Update(action),
+                                actant_decline,
+                            )
+                        }
+                        EntryTypes::ClauseTransition(clause_transition) => {
+                            validate_create_clause_transition(
+                                EntryCreationAction::Update(action),
+                                clause_transition,
+                            )
+                        }
+                        EntryTypes::ClauseRatification(clause_ratification) => {
+                            validate_create_clause_ratification(
+                                EntryCreationAction::Update(action),
+                                clause_ratification,
+                            )
+                        }
+                        EntryTypes::ReportCorrection(report_correction) => {
+                            validate_create_report_correction(
+                                EntryCreationAction::Update(action),
+                                report_correction,
+                            )
+                        }
+                        EntryTypes::ReportRetraction(report_retraction) => {
+                            validate_create_report_retraction(
+                                EntryCreationAction::Update(action),
+                                report_retraction,
+                            )
+                        }
+                        EntryTypes::EndorsementRevocation(endorsement_revocation) => {
+                            validate_create_endorsement_revocation(
+                                EntryCreationAction::Update(action),
+                                endorsement_revocation,
+                            )
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
@@ -157,6 +371,105 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_actant,
                             )
                         }
+                        (
+                            EntryTypes::ClauseApproval(clause_approval),
+                            EntryTypes::ClauseApproval(original_clause_approval),
+                        ) => {
+                            validate_update_clause_approval(
+                                action,
+                                clause_approval,
+                                original_action,
+                                original_clause_approval,
+                            )
+                        }
+                        (
+                            EntryTypes::ActantInvitation(actant_invitation),
+                            EntryTypes::ActantInvitation(original_actant_invitation),
+                        ) => {
+                            validate_update_actant_invitation(
+                                action,
+                                actant_invitation,
+                                original_action,
+                                original_actant_invitation,
+                            )
+                        }
+                        (
+                            EntryTypes::ActantAcceptance(actant_acceptance),
+                            EntryTypes::ActantAcceptance(original_actant_acceptance),
+                        ) => {
+                            validate_update_actant_acceptance(
+                                action,
+                                actant_acceptance,
+                                original_action,
+                                original_actant_acceptance,
+                            )
+                        }
+                        (
+                            EntryTypes::ActantDecline(actant_decline),
+                            EntryTypes::ActantDecline(original_actant_decline),
+                        ) => {
+                            validate_update_actant_decline(
+                                action,
+                                actant_decline,
+                                original_action,
+                                original_actant_decline,
+                            )
+                        }
+                        (
+                            EntryTypes::ClauseTransition(clause_transition),
+                            EntryTypes::ClauseTransition(original_clause_transition),
+                        ) => {
+                            validate_update_clause_transition(
+                                action,
+                                clause_transition,
+                                original_action,
+                                original_clause_transition,
+                            )
+                        }
+                        (
+                            EntryTypes::ClauseRatification(clause_ratification),
+                            EntryTypes::ClauseRatification(original_clause_ratification),
+                        ) => {
+                            validate_update_clause_ratification(
+                                action,
+                                clause_ratification,
+                                original_action,
+                                original_clause_ratification,
+                            )
+                        }
+                        (
+                            EntryTypes::ReportCorrection(report_correction),
+                            EntryTypes::ReportCorrection(original_report_correction),
+                        ) => {
+                            validate_update_report_correction(
+                                action,
+                                report_correction,
+                                original_action,
+                                original_report_correction,
+                            )
+                        }
+                        (
+                            EntryTypes::ReportRetraction(report_retraction),
+                            EntryTypes::ReportRetraction(original_report_retraction),
+                        ) => {
+                            validate_update_report_retraction(
+                                action,
+                                report_retraction,
+                                original_action,
+                                original_report_retraction,
+                            )
+                        }
+                        (
+                            EntryTypes::EndorsementRevocation(endorsement_revocation),
+                            EntryTypes::EndorsementRevocation(original_endorsement_revocation),
+                        ) => {
+                            validate_update_endorsement_revocation(
+                                action,
+                                endorsement_revocation,
+                                original_action,
+                                original_endorsement_revocation,
+                            )
+                        }
                         _ => {
                             Ok(
                                 ValidateCallbackResult::Invalid(
@@ -190,6 +503,33 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 endorsement,
                             )
                         }
+                        EntryTypes::ClauseApproval(clause_approval) => {
+                            validate_delete_clause_approval(action, original_action, clause_approval)
+                        }
+                        EntryTypes::ActantInvitation(actant_invitation) => {
+                            validate_delete_actant_invitation(action, original_action, actant_invitation)
+                        }
+                        EntryTypes::ActantAcceptance(actant_acceptance) => {
+                            validate_delete_actant_acceptance(action, original_action, actant_acceptance)
+                        }
+                        EntryTypes::ActantDecline(actant_decline) => {
+                            validate_delete_actant_decline(action, original_action, actant_decline)
+                        }
+                        EntryTypes::ClauseTransition(clause_transition) => {
+                            validate_delete_clause_transition(action, original_action, clause_transition)
+                        }
+                        EntryTypes::ClauseRatification(clause_ratification) => {
+                            validate_delete_clause_ratification(action, original_action, clause_ratification)
+                        }
+                        EntryTypes::ReportCorrection(report_correction) => {
+                            validate_delete_report_correction(action, original_action, report_correction)
+                        }
+                        EntryTypes::ReportRetraction(report_retraction) => {
+                            validate_delete_report_retraction(action, original_action, report_retraction)
+                        }
+                        EntryTypes::EndorsementRevocation(endorsement_revocation) => {
+                            validate_delete_endorsement_revocation(action, original_action, endorsement_revocation)
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
@@ -259,6 +599,102 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
+                LinkTypes::ClauseProposals => {
+                    validate_create_link_clause_proposals(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ClauseApprovals => {
+                    validate_create_link_clause_approvals(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ClauseUpdates => {
+                    validate_create_link_clause_updates(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllActantsShards => {
+                    validate_create_link_all_actants_shards(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllClausesShards => {
+                    validate_create_link_all_clauses_shards(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToActants => {
+                    validate_create_link_agent_to_actants(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToInvitations => {
+                    validate_create_link_agent_to_invitations(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ClauseToTransitions => {
+                    validate_create_link_clause_to_transitions(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ClauseToRatifications => {
+                    validate_create_link_clause_to_ratifications(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ReportToCorrections => {
+                    validate_create_link_report_to_corrections(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ReportToRetractions => {
+                    validate_create_link_report_to_retractions(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::EndorsementToRevocations => {
+                    validate_create_link_endorsement_to_revocations(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
             }
         }
         FlatOp::RegisterDeleteLink {
@@ -333,71 +769,481 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
-            }
-        }
-        FlatOp::StoreRecord(store_record) => {
-            match store_record {
-                OpRecord::CreateEntry { app_entry, action } => {
-                    match app_entry {
-                        EntryTypes::Actant(actant) => {
-                            validate_create_actant(
-                                EntryCreationAction::Create(action),
-                                actant,
-                            )
-                        }
-                        EntryTypes::Clause(clause) => {
-                            validate_create_clause(
-                                EntryCreationAction::Create(action),
-                                clause,
-                            )
-                        }
-                        EntryTypes::Report(report) => {
-                            validate_create_report(
-                                EntryCreationAction::Create(action),
-                                report,
-                            )
-                        }
-                        EntryTypes::Endorsement(endorsement) => {
-                            validate_create_endorsement(
-                                EntryCreationAction::Create(action),
-                                endorsement,
-                            )
-                        }
-                    }
+                LinkTypes::ClauseProposals => {
+                    validate_delete_link_clause_proposals(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
                 }
-                OpRecord::UpdateEntry {
-                    original_action_hash,
-                    app_entry,
-                    action,
-                    ..
-                } => {
-                    let original_record = must_get_valid_record(original_action_hash)?;
-                    let original_action = original_record.action().clone();
-                    let original_action = match original_action {
-                        Action::Create(create) => EntryCreationAction::Create(create),
-                        Action::Update(update) => EntryCreationAction::Update(update),
-                        _ => {
-                            return Ok(
-                                ValidateCallbackResult::Invalid(
-                                    "Original action for an update must be a Create or Update action"
-                                        .to_string(),
-                                ),
-                            );
+                LinkTypes::ClauseApprovals => {
+                    validate_delete_link_clause_approvals(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ClauseUpdates => {
+                    validate_delete_link_clause_updates(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllActantsShards => {
+                    validate_delete_link_all_actants_shards(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllClausesShards => {
+                    validate_delete_link_all_clauses_shards(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToActants => {
+                    validate_delete_link_agent_to_actants(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToInvitations => {
+                    validate_delete_link_agent_to_invitations(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ClauseToTransitions => {
+                    validate_delete_link_clause_to_transitions(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ClauseToRatifications => {
+                    validate_delete_link_clause_to_ratifications(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ReportToCorrections => {
+                    validate_delete_link_report_to_corrections(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ReportToRetractions => {
+                    validate_delete_link_report_to_retractions(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::EndorsementToRevocations => {
+                    validate_delete_link_endorsement_to_revocations(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+            }
+        }
+        FlatOp::StoreRecord(store_record) => {
+            match store_record {
+                OpRecord::CreateEntry { app_entry, action } => {
+                    match app_entry {
+                        EntryTypes::Actant(actant) => {
+                            validate_create_actant(
+                                EntryCreationAction::Create(action),
+                                actant,
+                            )
+                        }
+                        EntryTypes::Clause(clause) => {
+                            validate_create_clause(
+                                EntryCreationAction::Create(action),
+                                clause,
+                            )
+                        }
+                        EntryTypes::Report(report) => {
+                            validate_create_report(
+                                EntryCreationAction::Create(action),
+                                report,
+                            )
+                        }
+                        EntryTypes::Endorsement(endorsement) => {
+                            validate_create_endorsement(
+                                EntryCreationAction::Create(action),
+                                endorsement,
+                            )
+                        }
+                        EntryTypes::ClauseApproval(clause_approval) => {
+                            validate_create_clause_approval(
+                                EntryCreationAction::Create(action),
+                                clause_approval,
+                            )
+                        }
+                        EntryTypes::ActantInvitation(actant_invitation) => {
+                            validate_create_actant_invitation(
+                                EntryCreationAction::Create(action),
+                                actant_invitation,
+                            )
+                        }
+                        EntryTypes::ActantAcceptance(actant_acceptance) => {
+                            validate_create_actant_acceptance(
+                                EntryCreationAction::Create(action),
+                                actant_acceptance,
+                            )
+                        }
+                        EntryTypes::ActantDecline(actant_decline) => {
+                            validate_create_actant_decline(
+                                EntryCreationAction::Create(action),
+                                actant_decline,
+                            )
+                        }
+                        EntryTypes::ClauseTransition(clause_transition) => {
+                            validate_create_clause_transition(
+                                EntryCreationAction::Create(action),
+                                clause_transition,
+                            )
+                        }
+                        EntryTypes::ClauseRatification(clause_ratification) => {
+                            validate_create_clause_ratification(
+                                EntryCreationAction::Create(action),
+                                clause_ratification,
+                            )
+                        }
+                        EntryTypes::ReportCorrection(report_correction) => {
+                            validate_create_report_correction(
+                                EntryCreationAction::Create(action),
+                                report_correction,
+                            )
+                        }
+                        EntryTypes::ReportRetraction(report_retraction) => {
+                            validate_create_report_retraction(
+                                EntryCreationAction::Create(action),
+                                report_retraction,
+                            )
+                        }
+                        EntryTypes::EndorsementRevocation(endorsement_revocation) => {
+                            validate_create_endorsement_revocation(
+                                EntryCreationAction::Create(action),
+                                endorsement_revocation,
+                            )
+                        }
+                    }
+                }
+                OpRecord::UpdateEntry {
+                    original_action_hash,
+                    app_entry,
+                    action,
+                    ..
+                } => {
+                    let original_record = must_get_valid_record(original_action_hash)?;
+                    let original_action = original_record.action().clone();
+                    let original_action = match original_action {
+                        Action::Create(create) => EntryCreationAction::Create(create),
+                        Action::Update(update) => EntryCreationAction::Update(update),
+                        _ => {
+                            return Ok(
+                                ValidateCallbackResult::Invalid(
+                                    "Original action for an update must be a Create or Update action"
+                                        .to_string(),
+                                ),
+                            );
+                        }
+                    };
+                    match app_entry {
+                        EntryTypes::Actant(actant) => {
+                            let result = validate_create_actant(
+                                EntryCreationAction::Update(action.clone()),
+                                actant.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_actant: Option<Actant> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_actant = match original_actant {
+                                    Some(actant) => actant,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_actant(
+                                    action,
+                                    actant,
+                                    original_action,
+                                    original_actant,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Clause(clause) => {
+                            let result = validate_create_clause(
+                                EntryCreationAction::Update(action.clone()),
+                                clause.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_clause: Option<Clause> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_clause = match original_clause {
+                                    Some(clause) => clause,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_clause(
+                                    action,
+                                    clause,
+                                    original_action,
+                                    original_clause,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Report(report) => {
+                            let result = validate_create_report(
+                                EntryCreationAction::Update(action.clone()),
+                                report.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_report: Option<Report> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_report = match original_report {
+                                    Some(report) => report,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_report(
+                                    action,
+                                    report,
+                                    original_action,
+                                    original_report,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Endorsement(endorsement) => {
+                            let result = validate_create_endorsement(
+                                EntryCreationAction::Update(action.clone()),
+                                endorsement.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_endorsement: Option<Endorsement> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_endorsement = match original_endorsement {
+                                    Some(endorsement) => endorsement,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_endorsement(
+                                    action,
+                                    endorsement,
+                                    original_action,
+                                    original_endorsement,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::ClauseApproval(clause_approval) => {
+                            let result = validate_create_clause_approval(
+                                EntryCreationAction::Update(action.clone()),
+                                clause_approval.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_clause_approval: Option<ClauseApproval> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_clause_approval = match original_clause_approval {
+                                    Some(clause_approval) => clause_approval,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_clause_approval(
+                                    action,
+                                    clause_approval,
+                                    original_action,
+                                    original_clause_approval,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::ActantInvitation(actant_invitation) => {
+                            let result = validate_create_actant_invitation(
+                                EntryCreationAction::Update(action.clone()),
+                                actant_invitation.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_actant_invitation: Option<ActantInvitation> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_actant_invitation = match original_actant_invitation {
+                                    Some(actant_invitation) => actant_invitation,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_actant_invitation(
+                                    action,
+                                    actant_invitation,
+                                    original_action,
+                                    original_actant_invitation,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::ActantAcceptance(actant_acceptance) => {
+                            let result = validate_create_actant_acceptance(
+                                EntryCreationAction::Update(action.clone()),
+                                actant_acceptance.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_actant_acceptance: Option<ActantAcceptance> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_actant_acceptance = match original_actant_acceptance {
+                                    Some(actant_acceptance) => actant_acceptance,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_actant_acceptance(
+                                    action,
+                                    actant_acceptance,
+                                    original_action,
+                                    original_actant_acceptance,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::ActantDecline(actant_decline) => {
+                            let result = validate_create_actant_decline(
+                                EntryCreationAction::Update(action.clone()),
+                                actant_decline.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_actant_decline: Option<ActantDecline> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_actant_decline = match original_actant_decline {
+                                    Some(actant_decline) => actant_decline,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_actant_decline(
+                                    action,
+                                    actant_decline,
+                                    original_action,
+                                    original_actant_decline,
+                                )
+                            } else {
+                                Ok(result)
+                            }
                         }
-                    };
-                    match app_entry {
-                        EntryTypes::Actant(actant) => {
-                            let result = validate_create_actant(
+                        EntryTypes::ClauseTransition(clause_transition) => {
+                            let result = validate_create_clause_transition(
                                 EntryCreationAction::Update(action.clone()),
-                                actant.clone(),
+                                clause_transition.clone(),
                             )?;
                             if let ValidateCallbackResult::Valid = result {
-                                let original_actant: Option<Actant> = original_record
+                                let original_clause_transition: Option<ClauseTransition> = original_record
                                     .entry()
                                     .to_app_option()
                                     .map_err(|e| wasm_error!(e))?;
-                                let original_actant = match original_actant {
-                                    Some(actant) => actant,
+                                let original_clause_transition = match original_clause_transition {
+                                    Some(clause_transition) => clause_transition,
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
@@ -407,28 +1253,28 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                         );
                                     }
                                 };
-                                validate_update_actant(
+                                validate_update_clause_transition(
                                     action,
-                                    actant,
+                                    clause_transition,
                                     original_action,
-                                    original_actant,
+                                    original_clause_transition,
                                 )
                             } else {
                                 Ok(result)
                             }
                         }
-                        EntryTypes::Clause(clause) => {
-                            let result = validate_create_clause(
+                        EntryTypes::ClauseRatification(clause_ratification) => {
+                            let result = validate_create_clause_ratification(
                                 EntryCreationAction::Update(action.clone()),
-                                clause.clone(),
+                                clause_ratification.clone(),
                             )?;
                             if let ValidateCallbackResult::Valid = result {
-                                let original_clause: Option<Clause> = original_record
+                                let original_clause_ratification: Option<ClauseRatification> = original_record
                                     .entry()
                                     .to_app_option()
                                     .map_err(|e| wasm_error!(e))?;
-                                let original_clause = match original_clause {
-                                    Some(clause) => clause,
+                                let original_clause_ratification = match original_clause_ratification {
+                                    Some(clause_ratification) => clause_ratification,
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
@@ -438,28 +1284,28 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                         );
                                     }
                                 };
-                                validate_update_clause(
+                                validate_update_clause_ratification(
                                     action,
-                                    clause,
+                                    clause_ratification,
                                     original_action,
-                                    original_clause,
+                                    original_clause_ratification,
                                 )
                             } else {
                                 Ok(result)
                             }
                         }
-                        EntryTypes::Report(report) => {
-                            let result = validate_create_report(
+                        EntryTypes::ReportCorrection(report_correction) => {
+                            let result = validate_create_report_correction(
                                 EntryCreationAction::Update(action.clone()),
-                                report.clone(),
+                                report_correction.clone(),
                             )?;
                             if let ValidateCallbackResult::Valid = result {
-                                let original_report: Option<Report> = original_record
+                                let original_report_correction: Option<ReportCorrection> = original_record
                                     .entry()
                                     .to_app_option()
                                     .map_err(|e| wasm_error!(e))?;
-                                let original_report = match original_report {
-                                    Some(report) => report,
+                                let original_report_correction = match original_report_correction {
+                                    Some(report_correction) => report_correction,
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
@@ -469,28 +1315,28 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                         );
                                     }
                                 };
-                                validate_update_report(
+                                validate_update_report_correction(
                                     action,
-                                    report,
+                                    report_correction,
                                     original_action,
-                                    original_report,
+                                    original_report_correction,
                                 )
                             } else {
                                 Ok(result)
                             }
                         }
-                        EntryTypes::Endorsement(endorsement) => {
-                            let result = validate_create_endorsement(
+                        EntryTypes::ReportRetraction(report_retraction) => {
+                            let result = validate_create_report_retraction(
                                 EntryCreationAction::Update(action.clone()),
-                                endorsement.clone(),
+                                report_retraction.clone(),
                             )?;
                             if let ValidateCallbackResult::Valid = result {
-                                let original_endorsement: Option<Endorsement> = original_record
+                                let original_report_retraction: Option<ReportRetraction> = original_record
                                     .entry()
                                     .to_app_option()
                                     .map_err(|e| wasm_error!(e))?;
-                                let original_endorsement = match original_endorsement {
-                                    Some(endorsement) => endorsement,
+                                let original_report_retraction = match original_report_retraction {
+                                    Some(report_retraction) => report_retraction,
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
@@ -500,11 +1346,42 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                         );
                                     }
                                 };
-                                validate_update_endorsement(
+                                validate_update_report_retraction(
                                     action,
-                                    endorsement,
+                                    report_retraction,
                                     original_action,
-                                    original_endorsement,
+                                    original_report_retraction,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::EndorsementRevocation(endorsement_revocation) => {
+                            let result = validate_create_endorsement_revocation(
+                                EntryCreationAction::Update(action.clone()),
+                                endorsement_revocation.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_endorsement_revocation: Option<EndorsementRevocation> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_endorsement_revocation = match original_endorsement_revocation {
+                                    Some(endorsement_revocation) => endorsement_revocation,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_endorsement_revocation(
+                                    action,
+                                    endorsement_revocation,
+                                    original_action,
+                                    original_endorsement_revocation,
                                 )
                             } else {
                                 Ok(result)
@@ -592,6 +1469,69 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_endorsement,
                             )
                         }
+                        EntryTypes::ClauseApproval(original_clause_approval) => {
+                            validate_delete_clause_approval(
+                                action,
+                                original_action,
+                                original_clause_approval,
+                            )
+                        }
+                        EntryTypes::ActantInvitation(original_actant_invitation) => {
+                            validate_delete_actant_invitation(
+                                action,
+                                original_action,
+                                original_actant_invitation,
+                            )
+                        }
+                        EntryTypes::ActantAcceptance(original_actant_acceptance) => {
+                            validate_delete_actant_acceptance(
+                                action,
+                                original_action,
+                                original_actant_acceptance,
+                            )
+                        }
+                        EntryTypes::ActantDecline(original_actant_decline) => {
+                            validate_delete_actant_decline(
+                                action,
+                                original_action,
+                                original_actant_decline,
+                            )
+                        }
+                        EntryTypes::ClauseTransition(original_clause_transition) => {
+                            validate_delete_clause_transition(
+                                action,
+                                original_action,
+                                original_clause_transition,
+                            )
+                        }
+                        EntryTypes::ClauseRatification(original_clause_ratification) => {
+                            validate_delete_clause_ratification(
+                                action,
+                                original_action,
+                                original_clause_ratification,
+                            )
+                        }
+                        EntryTypes::ReportCorrection(original_report_correction) => {
+                            validate_delete_report_correction(
+                                action,
+                                original_action,
+                                original_report_correction,
+                            )
+                        }
+                        EntryTypes::ReportRetraction(original_report_retraction) => {
+                            validate_delete_report_retraction(
+                                action,
+                                original_action,
+                                original_report_retraction,
+                            )
+                        }
+                        EntryTypes::EndorsementRevocation(original_endorsement_revocation) => {
+                            validate_delete_endorsement_revocation(
+                                action,
+                                original_action,
+                                original_endorsement_revocation,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreateLink {
@@ -658,6 +1598,102 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 tag,
                             )
                         }
+                        LinkTypes::ClauseProposals => {
+                            validate_create_link_clause_proposals(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ClauseApprovals => {
+                            validate_create_link_clause_approvals(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ClauseUpdates => {
+                            validate_create_link_clause_updates(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AllActantsShards => {
+                            validate_create_link_all_actants_shards(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AllClausesShards => {
+                            validate_create_link_all_clauses_shards(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToActants => {
+                            validate_create_link_agent_to_actants(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToInvitations => {
+                            validate_create_link_agent_to_invitations(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ClauseToTransitions => {
+                            validate_create_link_clause_to_transitions(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ClauseToRatifications => {
+                            validate_create_link_clause_to_ratifications(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ReportToCorrections => {
+                            validate_create_link_report_to_corrections(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ReportToRetractions => {
+                            validate_create_link_report_to_retractions(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::EndorsementToRevocations => {
+                            validate_create_link_endorsement_to_revocations(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::DeleteLink { original_action_hash, base_address, action } => {
@@ -746,6 +1782,114 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 create_link.tag,
                             )
                         }
+                        LinkTypes::ClauseProposals => {
+                            validate_delete_link_clause_proposals(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ClauseApprovals => {
+                            validate_delete_link_clause_approvals(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ClauseUpdates => {
+                            validate_delete_link_clause_updates(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AllActantsShards => {
+                            validate_delete_link_all_actants_shards(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AllClausesShards => {
+                            validate_delete_link_all_clauses_shards(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToActants => {
+                            validate_delete_link_agent_to_actants(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToInvitations => {
+                            validate_delete_link_agent_to_invitations(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ClauseToTransitions => {
+                            validate_delete_link_clause_to_transitions(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ClauseToRatifications => {
+                            validate_delete_link_clause_to_ratifications(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ReportToCorrections => {
+                            validate_delete_link_report_to_corrections(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ReportToRetractions => {
+                            validate_delete_link_report_to_retractions(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::EndorsementToRevocations => {
+                            validate_delete_link_endorsement_to_revocations(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
@@ -0,0 +1,17 @@
+use hdi::prelude::*;
+/// Properties a community can set in its DNA manifest
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, Default)]
+pub struct DnaProperties {
+    /// Report types allowed on top of the built-in ones
+    #[serde(default)]
+    pub custom_report_types: Vec<String>,
+}
+impl DnaProperties {
+    /// The DNA properties, or the defaults if none were set. Properties that do not match
+    /// this shape are an error rather than silently ignored
+    pub fn get() -> ExternResult<DnaProperties> {
+        let properties: Option<DnaProperties> = decode(dna_info()?.properties.bytes())
+            .map_err(|e| wasm_error!(e))?;
+        Ok(properties.unwrap_or_default())
+    }
+}
@@ -2,17 +2,138 @@ use hdi::prelude::*;
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct Report {
-    pub report_type: String,
+    pub report_type: ReportType,
     pub content: String,
     pub actant_hash: ActionHash,
     pub clause_hash: ActionHash,
+    /// Revision of the Actant the author reported as, filled with the latest one by
+    /// `create_report` when left empty. The author must be a member of it, and their own
+    /// chain must not show a later revision, but later revisions by other members that
+    /// the author has not seen are not visible to validation
+    #[serde(default)]
+    pub actant_revision_hash: Option<ActionHash>,
+    /// Transition that made the clause active, which `create_report` requires to be the
+    /// clause's current one and fills in when left empty. Validation rejects it if the
+    /// author's chain shows a later transition, but cannot see ones by other agents
+    #[serde(default)]
+    pub clause_transition_hash: Option<ActionHash>,
+    /// Revisions of the reporting Actant's guardians the author acts as, filled with the
+    /// latest ones by `create_report` when left empty
+    #[serde(default)]
+    pub guardian_revision_hashes: Vec<ActionHash>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ReportType {
+    Progress,
+    Breach,
+    Incident,
+    Fulfilment,
+    Other,
+    /// One of the `custom_report_types` listed in the DNA properties
+    Custom(String),
+}
+impl ReportType {
+    pub fn tag(&self) -> LinkTag {
+        match self {
+            ReportType::Progress => LinkTag::new("progress"),
+            ReportType::Breach => LinkTag::new("breach"),
+            ReportType::Incident => LinkTag::new("incident"),
+            ReportType::Fulfilment => LinkTag::new("fulfilment"),
+            ReportType::Other => LinkTag::new("other"),
+            ReportType::Custom(name) => LinkTag::new(format!("custom:{}", name)),
+        }
+    }
+}
+/// Whether `agent` may correct or retract the report: its author, or a member or guardian
+/// of the Actant revision it was filed as
+pub fn can_amend_report(
+    report_hash: &ActionHash,
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<bool> {
+    let record = must_get_valid_record(report_hash.clone())?;
+    if record.action().author() == agent {
+        return Ok(true);
+    }
+    let report: crate::Report = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    let revision_hash = report.actant_revision_hash.unwrap_or(report.actant_hash.clone());
+    match crate::must_get_actant_revision(&report.actant_hash, &revision_hash)? {
+        Some(actant) => {
+            crate::is_authorised_for_actant(
+                &report.actant_hash,
+                &revision_hash,
+                &actant,
+                &report.guardian_revision_hashes,
+                agent,
+                chain_top,
+            )
+        }
+        None => Ok(false),
+    }
 }
 pub fn validate_create_report(
-    _action: EntryCreationAction,
+    action: EntryCreationAction,
     report: Report,
 ) -> ExternResult<ValidateCallbackResult> {
-    let record = must_get_valid_record(report.actant_hash.clone())?;
-    let _actant: crate::Actant = record
+    if let ReportType::Custom(name) = &report.report_type {
+        if !crate::DnaProperties::get()?.custom_report_types.contains(name) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    format!("'{}' is not one of the report types of this network", name),
+                ),
+            );
+        }
+    }
+    let revision_hash = report
+        .actant_revision_hash
+        .clone()
+        .unwrap_or(report.actant_hash.clone());
+    let actant = match crate::must_get_actant_revision(
+        &report.actant_hash,
+        &revision_hash,
+    )? {
+        Some(actant) => actant,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The Actant revision must be an update of the reporting Actant"),
+                ),
+            );
+        }
+    };
+    if !crate::is_authorised_for_actant(
+        &report.actant_hash,
+        &revision_hash,
+        &actant,
+        &report.guardian_revision_hashes,
+        action.author(),
+        action.prev_action(),
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only members or guardians of the reporting Actant can create a report"),
+            ),
+        );
+    }
+    // Only the author's own chain is visible here: a revision superseded by other members
+    // is still accepted until the author updates, cites or accepts a later one
+    if crate::is_revision_superseded_by(&revision_hash, action.author(), action.prev_action())? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Reports cannot cite an Actant revision older than one their author has seen"),
+            ),
+        );
+    }
+    let record = must_get_valid_record(report.clause_hash.clone())?;
+    let clause: crate::Clause = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -21,8 +142,25 @@ pub fn validate_create_report(
                 WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
             ),
         )?;
-    let record = must_get_valid_record(report.clause_hash.clone())?;
-    let _clause: crate::Clause = record
+    if !clause.responsibilty_holders.contains(&report.actant_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The reporting Actant must be a responsibility holder of the clause"),
+            ),
+        );
+    }
+    let transition_hash = match report.clause_transition_hash {
+        Some(transition_hash) => transition_hash,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Reports can only be created against active clauses"),
+                ),
+            );
+        }
+    };
+    let record = must_get_valid_record(transition_hash.clone())?;
+    let transition: crate::ClauseTransition = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -31,6 +169,29 @@ pub fn validate_create_report(
                 WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
             ),
         )?;
+    if transition.clause_hash != report.clause_hash
+        || transition.to != crate::ClauseStatus::Active
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Reports can only be created against active clauses"),
+            ),
+        );
+    }
+    // Only the author's own chain is visible here: a transition superseded by other agents
+    // is still accepted, but `create_report` refuses to cite anything but the current one
+    if crate::is_transition_superseded_by(
+        &report.clause_hash,
+        Some(&transition_hash),
+        action.author(),
+        action.prev_action(),
+    )? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Reports cannot cite a clause transition older than one their author has seen"),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_update_report(
@@ -49,12 +210,12 @@ pub fn validate_delete_report(
     Ok(ValidateCallbackResult::Invalid(String::from("Reports cannot be deleted")))
 }
 pub fn validate_create_link_actant_to_reports(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::from(base_address);
+    let action_hash = ActionHash::from(base_address.clone());
     let record = must_get_valid_record(action_hash)?;
     let _actant: crate::Actant = record
         .entry()
@@ -67,7 +228,7 @@ pub fn validate_create_link_actant_to_reports(
         )?;
     let action_hash = ActionHash::from(target_address);
     let record = must_get_valid_record(action_hash)?;
-    let _report: crate::Report = record
+    let report: crate::Report = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -76,6 +237,20 @@ pub fn validate_create_link_actant_to_reports(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
+    if AnyLinkableHash::from(report.actant_hash) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ActantToReports links must start from the reporting Actant"),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ActantToReports links must be created by the author of the report"),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_link_actant_to_reports(
@@ -92,12 +267,12 @@ pub fn validate_delete_link_actant_to_reports(
     )
 }
 pub fn validate_create_link_clause_to_reports(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
-    _tag: LinkTag,
+    tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::from(base_address);
+    let action_hash = ActionHash::from(base_address.clone());
     let record = must_get_valid_record(action_hash)?;
     let _clause: crate::Clause = record
         .entry()
@@ -110,7 +285,7 @@ pub fn validate_create_link_clause_to_reports(
         )?;
     let action_hash = ActionHash::from(target_address);
     let record = must_get_valid_record(action_hash)?;
-    let _report: crate::Report = record
+    let report: crate::Report = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -119,6 +294,27 @@ pub fn validate_create_link_clause_to_reports(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
+    if AnyLinkableHash::from(report.clause_hash) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ClauseToReports links must start from the reported clause"),
+            ),
+        );
+    }
+    if report.report_type.tag() != tag {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ClauseToReports links must be tagged with the type of the report"),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ClauseToReports links must be created by the author of the report"),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_link_clause_to_reports(
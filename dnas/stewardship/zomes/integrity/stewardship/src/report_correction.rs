@@ -0,0 +1,113 @@
+use hdi::prelude::*;
+/// Replaces the content of a report while the original stays on the DHT. Corrections form
+/// a chain through `previous_correction_hash` and the last one is the effective one, ties
+/// broken by hash so that every agent picks the same.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ReportCorrection {
+    pub report_hash: ActionHash,
+    pub content: String,
+    /// The correction this one follows, `None` for the first correction of the report
+    #[serde(default)]
+    pub previous_correction_hash: Option<ActionHash>,
+}
+pub fn validate_create_report_correction(
+    action: EntryCreationAction,
+    report_correction: ReportCorrection,
+) -> ExternResult<ValidateCallbackResult> {
+    if report_correction.content.trim().is_empty() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("A correction must have content"),
+            ),
+        );
+    }
+    if let Some(previous_correction_hash) = report_correction.previous_correction_hash.clone() {
+        let record = must_get_valid_record(previous_correction_hash)?;
+        let previous_correction: crate::ReportCorrection = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                ),
+            )?;
+        if previous_correction.report_hash != report_correction.report_hash {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The previous correction must belong to the same report"),
+                ),
+            );
+        }
+    }
+    if !crate::can_amend_report(&report_correction.report_hash, action.author(), action.prev_action())? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the reporter or a member or guardian of its Actant can correct a report"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_report_correction(
+    _action: Update,
+    _report_correction: ReportCorrection,
+    _original_action: EntryCreationAction,
+    _original_report_correction: ReportCorrection,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Report corrections cannot be updated")))
+}
+pub fn validate_delete_report_correction(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_report_correction: ReportCorrection,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Report corrections cannot be deleted")))
+}
+pub fn validate_create_link_report_to_corrections(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let report_correction: crate::ReportCorrection = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if AnyLinkableHash::from(report_correction.report_hash) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ReportToCorrections links must start from the corrected report"),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ReportToCorrections links must be created by the author of the correction"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_report_to_corrections(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("ReportToCorrections links cannot be deleted"),
+        ),
+    )
+}
@@ -0,0 +1,82 @@
+use hdi::prelude::*;
+/// Withdraws a report, which then no longer counts towards the clause it was filed against
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ReportRetraction {
+    pub report_hash: ActionHash,
+    pub reason: String,
+}
+pub fn validate_create_report_retraction(
+    action: EntryCreationAction,
+    report_retraction: ReportRetraction,
+) -> ExternResult<ValidateCallbackResult> {
+    if !crate::can_amend_report(&report_retraction.report_hash, action.author(), action.prev_action())? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the reporter or a member or guardian of its Actant can retract a report"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_report_retraction(
+    _action: Update,
+    _report_retraction: ReportRetraction,
+    _original_action: EntryCreationAction,
+    _original_report_retraction: ReportRetraction,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Report retractions cannot be updated")))
+}
+pub fn validate_delete_report_retraction(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_report_retraction: ReportRetraction,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Report retractions cannot be deleted")))
+}
+pub fn validate_create_link_report_to_retractions(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let report_retraction: crate::ReportRetraction = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if AnyLinkableHash::from(report_retraction.report_hash) != base_address {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ReportToRetractions links must start from the retracted report"),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("ReportToRetractions links must be created by the author of the retraction"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_report_to_retractions(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("ReportToRetractions links cannot be deleted"),
+        ),
+    )
+}
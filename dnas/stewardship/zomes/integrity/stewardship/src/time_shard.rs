@@ -0,0 +1,62 @@
+use hdi::prelude::*;
+/// UTC calendar day of a timestamp, as `[year, month, day]`
+pub fn shard_date(timestamp: Timestamp) -> [u32; 3] {
+    // Civil-from-days conversion, see http://howardhinnant.github.io/date_algorithms.html
+    let days = timestamp.as_micros().div_euclid(86_400_000_000) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524
+        - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    [year as u32, month as u32, day as u32]
+}
+/// Path of the day shard of a collection anchor, e.g. `all_actants.2023.10.01`
+pub fn time_shard_path(anchor: &str, timestamp: Timestamp) -> Path {
+    let [year, month, day] = shard_date(timestamp);
+    Path::from(format!("{}.{}.{:02}.{:02}", anchor, year, month, day))
+}
+/// The anchor, year, month and day paths leading to the day shard of `timestamp`
+fn time_shard_branch(anchor: &str, timestamp: Timestamp) -> Vec<Path> {
+    let [year, month, day] = shard_date(timestamp);
+    vec![
+        Path::from(anchor.to_string()),
+        Path::from(format!("{}.{}", anchor, year)),
+        Path::from(format!("{}.{}.{:02}", anchor, year, month)),
+        Path::from(format!("{}.{}.{:02}.{:02}", anchor, year, month, day)),
+    ]
+}
+/// Validates a path tree link of a time sharded collection: it must join two consecutive
+/// paths of the branch of the day the link was created, or of the day before for records
+/// created just before midnight, and be tagged with the child's leaf component
+pub fn validate_time_shard_link(
+    anchor: &str,
+    action: &CreateLink,
+    base_address: &AnyLinkableHash,
+    target_address: &AnyLinkableHash,
+    tag: &LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let previous_day = Timestamp::from_micros(action.timestamp.as_micros() - 86_400_000_000);
+    for timestamp in [action.timestamp, previous_day] {
+        let branch = time_shard_branch(anchor, timestamp);
+        for (depth, path) in branch.iter().enumerate() {
+            let parent_hash = match depth {
+                0 => root_hash()?,
+                _ => AnyLinkableHash::from(branch[depth - 1].path_entry_hash()?),
+            };
+            if &parent_hash == base_address
+                && &AnyLinkableHash::from(path.path_entry_hash()?) == target_address
+                && &path.make_tag()? == tag
+            {
+                return Ok(ValidateCallbackResult::Valid);
+            }
+        }
+    }
+    Ok(ValidateCallbackResult::Invalid(format!(
+        "Shard links must join the {} anchor and its year, month and day shards",
+        anchor
+    )))
+}
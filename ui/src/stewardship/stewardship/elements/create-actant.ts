@@ -67,12 +67,21 @@ export class CreateActant extends LitElement {
   _agentsFields = [0];
 
   async createActant(fields: any) {
+    const myPubKey = this.stewardshipStore.client.client.myPubKey;
+    const agents: Array<AgentPubKey> = (Array.isArray(fields.agents)
+      ? fields.agents
+      : [fields.agents]
+    ).map((el: any) => el);
+    // The creator becomes the first admin, so it must be one of the agents
+    if (!agents.some(agent => agent.toString() === myPubKey.toString())) {
+      agents.unshift(myPubKey);
+    }
     const actant: Actant = {
-      agents: (Array.isArray(fields.agents)
-        ? fields.agents
-        : [fields.agents]
-      ).map((el: any) => el),
+      agents,
+      admins: [myPubKey],
       name: fields.name,
+      kind: 'Human',
+      guardians: [],
     };
 
     try {
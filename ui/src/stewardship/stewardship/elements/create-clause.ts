@@ -45,6 +45,8 @@ import { Actant, Clause } from '../types.js';
 
 type HolderType = 'right-holders' | 'responsibility-holders';
 
+const DAY = 24 * 60 * 60;
+
 /**
  * @element create-clause
  * @fires clause-created: detail will contain { clauseHash }
@@ -102,6 +104,18 @@ export class CreateClause extends LitElement {
       statement: fields.statement,
       right_holders,
       responsibilty_holders,
+      endorsement_quorum: Math.min(
+        parseInt(fields.endorsement_quorum, 10),
+        right_holders.length
+      ),
+      reporting_schedule: fields.reporting_interval_days
+        ? {
+            start: Date.now() * 1000,
+            interval_secs: parseInt(fields.reporting_interval_days, 10) * DAY,
+            grace_period_secs:
+              parseInt(fields.grace_period_days || '0', 10) * DAY,
+          }
+        : undefined,
     };
 
     try {
@@ -227,6 +241,36 @@ export class CreateClause extends LitElement {
           <h4>Right Holders</h4>
           ${this.renderActantSelect('right-holders')}
         </div>
+        <div style="margin-bottom: 16px;">
+          <sl-input
+            name="endorsement_quorum"
+            type="number"
+            min="1"
+            value="1"
+            .label=${msg('Endorsement Quorum')}
+            .helpText=${msg(
+              'Right holders that must endorse a report to verify it'
+            )}
+            required
+          ></sl-input>
+        </div>
+        <div style="margin-bottom: 16px;">
+          <sl-input
+            name="reporting_interval_days"
+            type="number"
+            min="1"
+            .label=${msg('Reporting Interval (days)')}
+            .helpText=${msg('Leave empty if reports are not due periodically')}
+          ></sl-input>
+        </div>
+        <div style="margin-bottom: 16px;">
+          <sl-input
+            name="grace_period_days"
+            type="number"
+            min="0"
+            .label=${msg('Grace Period (days)')}
+          ></sl-input>
+        </div>
         <div style="margin-bottom: 16px;">
           <h4>Responsibility Holders</h4>
           ${this.renderActantSelect('responsibility-holders')}
@@ -24,13 +24,17 @@ import '@shoelace-style/shoelace/dist/components/button/button.js';
 import '@shoelace-style/shoelace/dist/components/card/card.js';
 import '@shoelace-style/shoelace/dist/components/icon-button/icon-button.js';
 import '@shoelace-style/shoelace/dist/components/icon/icon.js';
+import '@shoelace-style/shoelace/dist/components/option/option.js';
+import '@shoelace-style/shoelace/dist/components/range/range.js';
+import '@shoelace-style/shoelace/dist/components/select/select.js';
+import '@shoelace-style/shoelace/dist/components/textarea/textarea.js';
 import { LitElement, html } from 'lit';
 import { customElement, property, query, state } from 'lit/decorators.js';
 import { repeat } from 'lit/directives/repeat.js';
 
 import { stewardshipStoreContext } from '../context.js';
 import { StewardshipStore } from '../stewardship-store.js';
-import { Endorsement } from '../types.js';
+import { Endorsement, MAX_CONFIDENCE } from '../types.js';
 
 /**
  * @element create-endorsement
@@ -69,6 +73,9 @@ export class CreateEndorsement extends LitElement {
 
     const endorsement: Endorsement = {
       report_hash: this.reportHash,
+      stance: fields.stance,
+      comment: fields.comment ? fields.comment : undefined,
+      confidence: parseInt(fields.confidence, 10),
     };
 
     try {
@@ -103,6 +110,34 @@ export class CreateEndorsement extends LitElement {
         style="display: flex; flex: 1; flex-direction: column;"
         ${onSubmit(fields => this.createEndorsement(fields))}
       >
+        <div style="margin-bottom: 16px;">
+          <sl-select name="stance" .label=${msg('Stance')} value="Endorse">
+            <sl-option value="Endorse">${msg('Endorse')}</sl-option>
+            <sl-option value="PartiallyEndorse"
+              >${msg('Partially endorse')}</sl-option
+            >
+            <sl-option value="Dispute">${msg('Dispute')}</sl-option>
+          </sl-select>
+        </div>
+
+        <div style="margin-bottom: 16px;">
+          <sl-textarea
+            name="comment"
+            .label=${msg('Comment')}
+            .helpText=${msg('Required to partially endorse or dispute')}
+          ></sl-textarea>
+        </div>
+
+        <div style="margin-bottom: 16px;">
+          <sl-range
+            name="confidence"
+            .label=${msg('Confidence')}
+            min="0"
+            .max=${MAX_CONFIDENCE}
+            .value=${MAX_CONFIDENCE}
+          ></sl-range>
+        </div>
+
         <sl-button variant="primary" type="submit" .loading=${this.committing}
           >${msg('Create Endorsement')}</sl-button
         >
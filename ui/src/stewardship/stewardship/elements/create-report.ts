@@ -32,7 +32,7 @@ import { repeat } from 'lit/directives/repeat.js';
 
 import { stewardshipStoreContext } from '../context.js';
 import { StewardshipStore } from '../stewardship-store.js';
-import { Report } from '../types.js';
+import { Report, parseReportType } from '../types.js';
 import './actant-select.js';
 import { ActantSelect } from './actant-select.js';
 
@@ -82,7 +82,7 @@ export class CreateReport extends LitElement {
       return
     }
     const report: Report = {
-      report_type: fields.report_type,
+      report_type: parseReportType(fields.report_type),
       content: fields.content,
       actant_hash: actantHash,
       clause_hash: this.clauseHash,
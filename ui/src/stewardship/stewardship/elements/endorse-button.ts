@@ -20,11 +20,12 @@ import { until } from 'lit/directives/until.js';
 
 import { stewardshipStoreContext } from '../context.js';
 import { StewardshipStore } from '../stewardship-store.js';
-import { Endorsement } from '../types.js';
+import { Endorsement, MAX_CONFIDENCE } from '../types.js';
 
 /**
  * @element endorse-button
  * @fires endorsement-created: detail will contain { endorsementHash }
+ * @fires endorsement-revoked: detail will contain { endorsementHash }
  */
 @localized()
 @customElement('endorse-button')
@@ -63,6 +64,9 @@ export class EndorseButton extends LitElement {
 
     const endorsement: Endorsement = {
       report_hash: this.reportHash,
+      stance: 'Endorse',
+      comment: undefined,
+      confidence: MAX_CONFIDENCE,
     };
 
     try {
@@ -88,6 +92,27 @@ export class EndorseButton extends LitElement {
     this.committing = false;
   }
 
+  async revokeEndorsement(endorsementHash: ActionHash) {
+    try {
+      this.committing = true;
+      await this.stewardshipStore.client.revokeEndorsement(endorsementHash);
+
+      this.dispatchEvent(
+        new CustomEvent('endorsement-revoked', {
+          composed: true,
+          bubbles: true,
+          detail: {
+            endorsementHash,
+          },
+        })
+      );
+    } catch (e: any) {
+      console.error(e);
+      notifyError(msg('Error revoking the endorsement'));
+    }
+    this.committing = false;
+  }
+
   async renderContentAsync(endorsementHashes: ActionHash[]) {
     const records = await Promise.all(
       endorsementHashes.map(hash =>
@@ -95,12 +120,16 @@ export class EndorseButton extends LitElement {
       )
     );
     const myPubKey = this.stewardshipStore.client.client.myPubKey.toString();
-    const userHasEndorsed = records.some(
+    const myEndorsement = records.find(
       record => record?.action.author.toString() === myPubKey
     );
     return html`<h4>${records.length ?? 0} Endorsement(s)</h4>
-      ${userHasEndorsed
-        ? ''
+      ${myEndorsement
+        ? html`<sl-button
+            @click=${() => this.revokeEndorsement(myEndorsement.actionHash)}
+            .disable=${this.committing}
+            >Revoke endorsement</sl-button
+          >`
         : html`<sl-button
             @click=${this.createEndorsement}
             .disable=${this.committing}
@@ -22,7 +22,7 @@ import { customElement, property, state } from 'lit/decorators.js';
 
 import { stewardshipStoreContext } from '../context.js';
 import { StewardshipStore } from '../stewardship-store.js';
-import { Endorsement } from '../types.js';
+import { Endorsement, EndorsementStance } from '../types.js';
 
 /**
  * @element endorsement-detail
@@ -54,10 +54,48 @@ export class EndorsementDetail extends LitElement {
         <span style="font-size: 18px; flex: 1;">${msg('Endorsement')}</span>
       </div>
 
-      <div style="display: flex; flex-direction: column"></div>
+      <div style="display: flex; flex-direction: column">
+        <div style="display: flex; flex-direction: column; margin-bottom: 16px">
+          <span style="margin-bottom: 8px"
+            ><strong>${msg('Stance')}</strong></span
+          >
+          <span>${this.stanceLabel(entryRecord.entry.stance)}</span>
+        </div>
+
+        <div style="display: flex; flex-direction: column; margin-bottom: 16px">
+          <span style="margin-bottom: 8px"
+            ><strong>${msg('Confidence')}</strong></span
+          >
+          <span>${entryRecord.entry.confidence}%</span>
+        </div>
+
+        ${entryRecord.entry.comment
+          ? html`<div
+              style="display: flex; flex-direction: column; margin-bottom: 16px"
+            >
+              <span style="margin-bottom: 8px"
+                ><strong>${msg('Comment')}</strong></span
+              >
+              <span style="white-space: pre-line"
+                >${entryRecord.entry.comment}</span
+              >
+            </div>`
+          : html``}
+      </div>
     </sl-card> `;
   }
 
+  stanceLabel(stance: EndorsementStance) {
+    switch (stance) {
+      case 'Endorse':
+        return msg('Endorse');
+      case 'PartiallyEndorse':
+        return msg('Partially endorse');
+      case 'Dispute':
+        return msg('Dispute');
+    }
+  }
+
   render() {
     switch (this._endorsement.value.status) {
       case 'pending':
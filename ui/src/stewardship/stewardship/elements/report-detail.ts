@@ -6,7 +6,6 @@ import {
 } from '@holochain-open-dev/elements';
 import '@holochain-open-dev/elements/dist/elements/display-error.js';
 import { StoreSubscriber } from '@holochain-open-dev/stores';
-import { EntryRecord } from '@holochain-open-dev/utils';
 import { ActionHash, EntryHash, Record } from '@holochain/client';
 import { consume } from '@lit-labs/context';
 import { localized, msg } from '@lit/localize';
@@ -22,7 +21,7 @@ import { customElement, property, state } from 'lit/decorators.js';
 
 import { stewardshipStoreContext } from '../context.js';
 import { StewardshipStore } from '../stewardship-store.js';
-import { Report } from '../types.js';
+import { EffectiveReport, reportTypeLabel } from '../types.js';
 
 /**
  * @element report-detail
@@ -48,19 +47,51 @@ export class ReportDetail extends LitElement {
     this.stewardshipStore.reports.get(this.reportHash)
   );
 
-  renderDetail(entryRecord: EntryRecord<Report>) {
+  /**
+   * @internal
+   */
+  _status = new StoreSubscriber(this, () =>
+    this.stewardshipStore.reportStatuses.get(this.reportHash)
+  );
+
+  renderStatus() {
+    if (this._status.value.status !== 'complete') return html``;
+    const { status, quorum, endorsing_actants } = this._status.value.value;
+    const label =
+      status === 'Verified'
+        ? msg('Verified')
+        : status === 'Disputed'
+        ? msg('Disputed')
+        : msg('Pending');
+    return html`<div
+      style="display: flex; flex-direction: column; margin-bottom: 16px"
+    >
+      <span style="margin-bottom: 8px"><strong>${msg('Status')}</strong></span>
+      <span
+        >${label} (${endorsing_actants.length}/${quorum}
+        ${msg('endorsements')})</span
+      >
+    </div>`;
+  }
+
+  renderDetail(effectiveReport: EffectiveReport) {
     return html`<sl-card>
       <div slot="header" style="display: flex; flex-direction: row">
         <span style="font-size: 18px; flex: 1;">${msg('Report')}</span>
       </div>
 
       <div style="display: flex; flex-direction: column">
+        ${effectiveReport.retracted
+          ? html`<span style="margin-bottom: 16px"
+              ><strong>${msg('This report has been retracted')}</strong></span
+            >`
+          : html``}
         <div style="display: flex; flex-direction: column; margin-bottom: 16px">
           <span style="margin-bottom: 8px"
             ><strong>${msg('Report Type')}</strong></span
           >
           <span style="white-space: pre-line"
-            >${entryRecord.entry.report_type}</span
+            >${reportTypeLabel(effectiveReport.report.report_type)}</span
           >
         </div>
 
@@ -69,9 +100,15 @@ export class ReportDetail extends LitElement {
             ><strong>${msg('Content')}</strong></span
           >
           <span style="white-space: pre-line"
-            >${entryRecord.entry.content}</span
+            >${effectiveReport.report.content}</span
           >
+          ${effectiveReport.correction_hash
+            ? html`<span style="margin-top: 8px"
+                >${msg('Corrected')}</span
+              >`
+            : html``}
         </div>
+        ${this.renderStatus()}
       </div>
     </sl-card> `;
   }
@@ -1,7 +1,6 @@
 import { hashProperty, sharedStyles } from '@holochain-open-dev/elements';
 import '@holochain-open-dev/elements/dist/elements/display-error.js';
 import { StoreSubscriber } from '@holochain-open-dev/stores';
-import { EntryRecord } from '@holochain-open-dev/utils';
 import { ActionHash, EntryHash, Record } from '@holochain/client';
 import { consume } from '@lit-labs/context';
 import { localized, msg } from '@lit/localize';
@@ -12,7 +11,7 @@ import { customElement, property, state } from 'lit/decorators.js';
 
 import { stewardshipStoreContext } from '../context';
 import { StewardshipStore } from '../stewardship-store';
-import { Report } from '../types';
+import { EffectiveReport, reportTypeLabel } from '../types';
 import './endorse-button.js';
 
 /**
@@ -39,15 +38,20 @@ export class ReportSummary extends LitElement {
     this.stewardshipStore.reports.get(this.reportHash)
   );
 
-  renderSummary(entryRecord: EntryRecord<Report>) {
+  renderSummary(effectiveReport: EffectiveReport) {
     return html`
       <div style="display: flex; flex-direction: column">
+        ${effectiveReport.retracted
+          ? html`<span style="margin-bottom: 16px"
+              ><strong>${msg('This report has been retracted')}</strong></span
+            >`
+          : html``}
         <div style="display: flex; flex-direction: column; margin-bottom: 16px">
           <span style="margin-bottom: 8px"
             ><strong>${msg('Report Type')}</strong></span
           >
           <span style="white-space: pre-line"
-            >${entryRecord.entry.report_type}</span
+            >${reportTypeLabel(effectiveReport.report.report_type)}</span
           >
         </div>
 
@@ -56,8 +60,13 @@ export class ReportSummary extends LitElement {
             ><strong>${msg('Content')}</strong></span
           >
           <span style="white-space: pre-line"
-            >${entryRecord.entry.content}</span
+            >${effectiveReport.report.content}</span
           >
+          ${effectiveReport.correction_hash
+            ? html`<span style="margin-top: 8px"
+                >${msg('Corrected')}</span
+              >`
+            : html``}
         </div>
         <endorse-button .reportHash=${this.reportHash}></endorse-button>
       </div>
@@ -11,9 +11,19 @@ import {
   Record,
 } from '@holochain/client';
 
-import { Endorsement } from './types';
-import { Report } from './types';
-import { Clause } from './types';
+import {
+  Endorsement,
+  EndorsementStanceCounts,
+  EndorsementWithRevocation,
+} from './types';
+import { EffectiveReport, Report, ReportStatus } from './types';
+import {
+  Clause,
+  ClauseCompliance,
+  ClauseRole,
+  ClauseStatus,
+  OverdueObligation,
+} from './types';
 import { Actant } from './types';
 import { StewardshipSignal } from './types.js';
 
@@ -69,21 +79,54 @@ export class StewardshipClient extends ZomeClient<StewardshipSignal> {
     return record ? new EntryRecord(record) : undefined;
   }
 
+  getClauseState(
+    clauseHash: ActionHash
+  ): Promise<{ status: ClauseStatus; transition_hash: ActionHash | undefined }> {
+    return this.callZome('get_clause_state', clauseHash);
+  }
+
+  async transitionClause(
+    clauseHash: ActionHash,
+    to: ClauseStatus
+  ): Promise<Record> {
+    return this.callZome('transition_clause', {
+      clause_hash: clauseHash,
+      to,
+    });
+  }
+
   deleteClause(originalClauseHash: ActionHash): Promise<ActionHash> {
     return this.callZome('delete_clause', originalClauseHash);
   }
 
   async getClausesForActant(
-    actantHash: ActionHash
-  ): Promise<Array<EntryRecord<Clause>>> {
-    const records: Record[] = await this.callZome(
-      'get_clauses_for_actant',
-      actantHash
-    );
-    return records.map(r => new EntryRecord(r));
+    actantHash: ActionHash,
+    role?: ClauseRole
+  ): Promise<Array<{ role: ClauseRole; record: EntryRecord<Clause> }>> {
+    const clauses: Array<{ role: ClauseRole; record: Record }> =
+      await this.callZome('get_clauses_for_actant', {
+        actant_hash: actantHash,
+        role,
+      });
+    return clauses.map(c => ({
+      role: c.role,
+      record: new EntryRecord(c.record),
+    }));
   }
   /** Report */
 
+  async getComplianceSummary(
+    actantHash: ActionHash
+  ): Promise<Array<ClauseCompliance>> {
+    return this.callZome('get_compliance_summary', actantHash);
+  }
+
+  async getOverdueObligations(
+    actantHash?: ActionHash
+  ): Promise<Array<OverdueObligation>> {
+    return this.callZome('get_overdue_obligations', actantHash ?? null);
+  }
+
   async createReport(report: Report): Promise<EntryRecord<Report>> {
     const record: Record = await this.callZome('create_report', report);
     return new EntryRecord(record);
@@ -91,29 +134,43 @@ export class StewardshipClient extends ZomeClient<StewardshipSignal> {
 
   async getReport(
     reportHash: ActionHash
-  ): Promise<EntryRecord<Report> | undefined> {
-    const record: Record = await this.callZome('get_report', reportHash);
-    return record ? new EntryRecord(record) : undefined;
+  ): Promise<EffectiveReport | undefined> {
+    return this.callZome('get_report', reportHash);
   }
 
   async getReportsForActant(
     actantHash: ActionHash
-  ): Promise<Array<EntryRecord<Report>>> {
-    const records: Record[] = await this.callZome(
-      'get_reports_for_actant',
-      actantHash
-    );
-    return records.map(r => new EntryRecord(r));
+  ): Promise<Array<EffectiveReport>> {
+    return this.callZome('get_reports_for_actant', actantHash);
   }
 
   async getReportsForClause(
     clauseHash: ActionHash
-  ): Promise<Array<EntryRecord<Report>>> {
-    const records: Record[] = await this.callZome(
-      'get_reports_for_clause',
-      clauseHash
-    );
-    return records.map(r => new EntryRecord(r));
+  ): Promise<Array<EffectiveReport>> {
+    return this.callZome('get_reports_for_clause', clauseHash);
+  }
+  async getReportStatus(reportHash: ActionHash): Promise<ReportStatus> {
+    return this.callZome('get_report_status', reportHash);
+  }
+
+  async correctReport(
+    reportHash: ActionHash,
+    content: string
+  ): Promise<EffectiveReport> {
+    return this.callZome('correct_report', {
+      report_hash: reportHash,
+      content,
+    });
+  }
+
+  async retractReport(
+    reportHash: ActionHash,
+    reason: string
+  ): Promise<EffectiveReport> {
+    return this.callZome('retract_report', {
+      report_hash: reportHash,
+      reason,
+    });
   }
   /** Endorsement */
 
@@ -138,19 +195,40 @@ export class StewardshipClient extends ZomeClient<StewardshipSignal> {
   }
 
   async getEndorsementsForReport(
+    reportHash: ActionHash,
+    includeRevoked = false
+  ): Promise<Array<EndorsementWithRevocation>> {
+    return this.callZome('get_endorsements_for_report', {
+      report_hash: reportHash,
+      include_revoked: includeRevoked,
+    });
+  }
+
+  async getEndorsementStanceCounts(
     reportHash: ActionHash
-  ): Promise<Array<EntryRecord<Endorsement>>> {
-    const records: Record[] = await this.callZome(
-      'get_endorsements_for_report',
-      reportHash
-    );
-    return records.map(r => new EntryRecord(r));
+  ): Promise<EndorsementStanceCounts> {
+    return this.callZome('get_endorsement_stance_counts', reportHash);
+  }
+
+  async revokeEndorsement(
+    endorsementHash: ActionHash,
+    reason?: string
+  ): Promise<Record> {
+    return this.callZome('revoke_endorsement', {
+      endorsement_hash: endorsementHash,
+      reason: reason ?? null,
+    });
   }
 
   /** All Clauses */
 
-  async getAllClauses(): Promise<Array<EntryRecord<Clause>>> {
-    const records: Record[] = await this.callZome('get_all_clauses', null);
+  async getAllClauses(
+    status?: ClauseStatus
+  ): Promise<Array<EntryRecord<Clause>>> {
+    const records: Record[] = await this.callZome(
+      'get_all_clauses',
+      status ?? null
+    );
     return records.map(r => new EntryRecord(r));
   }
 
@@ -31,8 +31,8 @@ export class StewardshipStore {
 
   clausesForActant = new LazyHoloHashMap((actantHash: ActionHash) =>
     lazyLoadAndPoll(async () => {
-      const records = await this.client.getClausesForActant(actantHash);
-      return records.map(r => r.actionHash);
+      const clauses = await this.client.getClausesForActant(actantHash);
+      return clauses.map(c => c.record.actionHash);
     }, 4000)
   );
 
@@ -42,17 +42,21 @@ export class StewardshipStore {
     lazyLoadAndPoll(async () => this.client.getReport(reportHash), 4000)
   );
 
+  reportStatuses = new LazyHoloHashMap((reportHash: ActionHash) =>
+    lazyLoadAndPoll(async () => this.client.getReportStatus(reportHash), 4000)
+  );
+
   reportsForActant = new LazyHoloHashMap((actantHash: ActionHash) =>
     lazyLoadAndPoll(async () => {
-      const records = await this.client.getReportsForActant(actantHash);
-      return records.map(r => r.actionHash);
+      const reports = await this.client.getReportsForActant(actantHash);
+      return reports.map(r => r.record.signed_action.hashed.hash);
     }, 4000)
   );
 
   reportsForClause = new LazyHoloHashMap((clauseHash: ActionHash) =>
     lazyLoadAndPoll(async () => {
-      const records = await this.client.getReportsForClause(clauseHash);
-      return records.map(r => r.actionHash);
+      const reports = await this.client.getReportsForClause(clauseHash);
+      return reports.map(r => r.record.signed_action.hashed.hash);
     }, 4000)
   );
 
@@ -67,8 +71,10 @@ export class StewardshipStore {
 
   endorsementsForReport = new LazyHoloHashMap((reportHash: ActionHash) =>
     lazyLoadAndPoll(async () => {
-      const records = await this.client.getEndorsementsForReport(reportHash);
-      return records.map(r => r.actionHash);
+      const endorsements = await this.client.getEndorsementsForReport(
+        reportHash
+      );
+      return endorsements.map(e => e.record.signed_action.hashed.hash);
     }, 4000)
   );
 
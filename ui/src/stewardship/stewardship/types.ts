@@ -9,6 +9,7 @@ import {
   EntryHash,
   Record,
   SignedActionHashed,
+  Timestamp,
   Update,
 } from '@holochain/client';
 
@@ -38,6 +39,17 @@ export type StewardshipSignal =
       type: 'LinkDeleted';
       action: SignedActionHashed<DeleteLink>;
       link_type: string;
+    }
+  | {
+      type: 'ReportVerified';
+      report_hash: ActionHash;
+      endorsement_hash: ActionHash;
+      /** Set when revoking the endorsement is what verified the report */
+      revocation_hash: ActionHash | undefined;
+    }
+  | {
+      type: 'OverdueObligations';
+      obligations: Array<OverdueObligation>;
     };
 
 export type EntryTypes =
@@ -49,9 +61,24 @@ export type EntryTypes =
 export interface Actant {
   agents: Array<AgentPubKey>;
 
+  admins: Array<AgentPubKey>;
+
   name: string;
+
+  kind: ActantKind;
+
+  guardians: Array<ActionHash>;
+
+  /** Latest revisions of the guardians, filled by the zome */
+  guardian_revision_hashes?: Array<ActionHash>;
 }
 
+export type ActantKind =
+  | 'Human'
+  | 'Organisation'
+  | 'Collective'
+  | 'MoreThanHuman';
+
 export interface Clause {
   title: string;
 
@@ -60,18 +87,173 @@ export interface Clause {
   right_holders: Array<ActionHash>;
 
   responsibilty_holders: Array<ActionHash>;
+
+  requires_ratification?: boolean;
+
+  /** Distinct right holders that must endorse a report to verify it, defaults to 1 */
+  endorsement_quorum?: number;
+
+  reporting_schedule?: ReportingSchedule;
+}
+
+/** One report is due per interval from `start` on, by the end of the interval plus the grace period */
+export interface ReportingSchedule {
+  start: Timestamp;
+
+  interval_secs: number;
+
+  grace_period_secs: number;
+}
+
+export interface OverdueObligation {
+  clause_hash: ActionHash;
+
+  actant_hash: ActionHash;
+
+  period_start: Timestamp;
+
+  due_at: Timestamp;
+
+  last_report_at: Timestamp | undefined;
+}
+
+export type ClauseRole = 'RightHolder' | 'ResponsibilityHolder';
+
+export type ReportType =
+  | 'Progress'
+  | 'Breach'
+  | 'Incident'
+  | 'Fulfilment'
+  | 'Other'
+  | { Custom: string };
+
+const BUILT_IN_REPORT_TYPES = [
+  'Progress',
+  'Breach',
+  'Incident',
+  'Fulfilment',
+  'Other',
+];
+
+/** Built-in report types are matched by name, anything else is a custom type */
+export function parseReportType(name: string): ReportType {
+  const builtIn = BUILT_IN_REPORT_TYPES.find(
+    t => t.toLowerCase() === name.trim().toLowerCase()
+  );
+  return builtIn ? (builtIn as ReportType) : { Custom: name.trim() };
+}
+
+export function reportTypeLabel(reportType: ReportType): string {
+  return typeof reportType === 'string' ? reportType : reportType.Custom;
 }
 
 export interface Report {
-  report_type: string;
+  report_type: ReportType;
 
   content: string;
 
   actant_hash: ActionHash;
 
   clause_hash: ActionHash;
+
+  actant_revision_hash?: ActionHash;
+
+  clause_transition_hash?: ActionHash;
+
+  /** Revisions of the reporting Actant's guardians, filled by the zome when left out */
+  guardian_revision_hashes?: Array<ActionHash>;
+}
+
+/** A report with its latest correction applied; `record` is the original */
+export interface EffectiveReport {
+  record: Record;
+
+  report: Report;
+
+  correction_hash: ActionHash | undefined;
+
+  retracted: boolean;
+
+  retraction_hash: ActionHash | undefined;
 }
 
+export type ClauseStatus =
+  | 'Draft'
+  | 'Proposed'
+  | 'Active'
+  | 'Suspended'
+  | 'Terminated';
+
+export type EndorsementStance = 'Endorse' | 'PartiallyEndorse' | 'Dispute';
+
+/** Highest confidence an endorser can state, as a percentage */
+export const MAX_CONFIDENCE = 100;
+
 export interface Endorsement {
   report_hash: ActionHash;
+
+  stance: EndorsementStance;
+
+  comment: string | undefined;
+
+  confidence: number;
+
+  /** Right holder the endorser speaks for, filled by the zome when left out */
+  actant_hash?: ActionHash;
+
+  /** Revisions of the right holder and its guardians, filled by the zome when left out */
+  actant_revision_hashes?: Array<ActionHash>;
+}
+
+export type VerificationStatus = 'Pending' | 'Verified' | 'Disputed';
+
+export interface ReportStatus {
+  status: VerificationStatus;
+
+  quorum: number;
+
+  endorsing_actants: Array<ActionHash>;
+
+  disputing_actants: Array<ActionHash>;
+}
+
+export type ComplianceStatus =
+  | 'Unreported'
+  | 'Pending'
+  | 'Compliant'
+  | 'Disputed'
+  | 'Breached';
+
+/** How an Actant is meeting a clause it is responsible for, leaving retracted reports out */
+export interface ClauseCompliance {
+  clause_hash: ActionHash;
+
+  report_count: number;
+
+  verified_report_count: number;
+
+  latest_report_at: Timestamp | undefined;
+
+  status: ComplianceStatus;
+}
+
+export interface EndorsementStanceCounts {
+  endorse: number;
+
+  partially_endorse: number;
+
+  dispute: number;
+}
+
+export interface EndorsementRevocation {
+  endorsement_hash: ActionHash;
+
+  reason: string | undefined;
+}
+
+/** An endorsement with its revocation, present only if it has been revoked */
+export interface EndorsementWithRevocation {
+  record: Record;
+
+  revocation: Record | undefined;
 }